@@ -0,0 +1,197 @@
+use crate::{Card, GameCommand, PendingInput, PlayerId, Round, Suit};
+
+/// Decisions of a computer opponent, asked whenever it is the player's turn.
+pub trait PlayerStrategy {
+    /// A bid the rules allow, see [`Round::validate_bid`].
+    fn bid(&mut self, round: &Round, player: PlayerId) -> u8;
+
+    /// Trump after the dealer turned up a Wizard.
+    fn choose_trump(&mut self, round: &Round, player: PlayerId) -> Suit;
+
+    /// One of [`Round::legal_moves`].
+    fn play(&mut self, round: &Round, player: PlayerId) -> Card;
+
+    /// The command for `player`, `None` if the round is not waiting for them.
+    fn command(&mut self, round: &Round, player: PlayerId) -> Option<GameCommand> {
+        match round.pending_input() {
+            PendingInput::Bid { player: next, .. } if next == player => Some(GameCommand::SetBid {
+                player,
+                bid: self.bid(round, player),
+            }),
+            PendingInput::Trump { player: next } if next == player => {
+                Some(GameCommand::ChooseTrump(self.choose_trump(round, player)))
+            }
+            PendingInput::Card { player: next } if next == player => {
+                Some(GameCommand::PlayCard(self.play(round, player)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Bids the tricks its hand is likely to take and plays the cheapest card that keeps it on
+/// track to hit that bid.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct HeuristicBot;
+
+impl HeuristicBot {
+    /// How likely the card takes a trick, high cards are worth less at a full table.
+    fn trick_chance(card: Card, trump: Option<Suit>, players: usize) -> f32 {
+        let crowd = 3.0 / players.max(3) as f32;
+        match card {
+            Card::Wizard => 1.0,
+            Card::Jester => 0.0,
+            Card::Number { suit, value } if Some(suit) == trump => match value {
+                11..=13 => 0.9,
+                7..=10 => 0.6,
+                _ => 0.3,
+            },
+            Card::Number { value: 13, .. } => 0.7 * crowd,
+            Card::Number { value: 12, .. } => 0.4 * crowd,
+            Card::Number { .. } => 0.0,
+        }
+    }
+
+    /// Orders cards from weakest to strongest for the given trump.
+    fn strength(card: Card, trump: Option<Suit>) -> u8 {
+        match card {
+            Card::Jester => 0,
+            Card::Number { suit, value } if Some(suit) == trump => 20 + value,
+            Card::Number { value, .. } => value,
+            Card::Wizard => 40,
+        }
+    }
+}
+
+impl PlayerStrategy for HeuristicBot {
+    fn bid(&mut self, round: &Round, player: PlayerId) -> u8 {
+        let hand = round.hand(player).unwrap_or_default();
+        let players = round.players.len();
+        let estimate: f32 = hand
+            .iter()
+            .map(|card| Self::trick_chance(*card, round.trump(), players))
+            .sum();
+        let estimate = estimate.round() as i32;
+
+        // the closest bid to the estimate the rules allow, e.g. for a dealer who may not
+        // make the bids add up
+        (0..=round.cards() as i32)
+            .filter(|bid| round.validate_bid(player, *bid as u8).is_ok())
+            .min_by_key(|bid| ((bid - estimate).abs(), *bid))
+            .unwrap_or(0) as u8
+    }
+
+    fn choose_trump(&mut self, round: &Round, player: PlayerId) -> Suit {
+        let hand = round.hand(player).unwrap_or_default();
+        let suit_value = |suit: Suit| -> (usize, u32) {
+            let cards = hand.iter().filter_map(|card| match card {
+                Card::Number { suit: other, value } if *other == suit => Some(*value as u32),
+                _ => None,
+            });
+            cards.fold((0, 0), |(count, sum), value| (count + 1, sum + value))
+        };
+
+        Suit::ALL
+            .into_iter()
+            .max_by_key(|suit| suit_value(*suit))
+            .unwrap_or(Suit::Red)
+    }
+
+    fn play(&mut self, round: &Round, player: PlayerId) -> Card {
+        let trump = round.trump();
+        let bid = round.tips.get_by_id(player).unwrap_or(0);
+        let won = round.matches.get_by_id(player).unwrap_or(0);
+        let trick = round.open_trick();
+
+        let mut moves = round.legal_moves(player);
+        moves.sort_by_key(|card| Self::strength(*card, trump));
+        let wins = |card: &Card| {
+            let mut trick = trick.clone();
+            trick.play(player, *card);
+            trick.winner() == Some(player)
+        };
+
+        let choice = if won < bid {
+            // the cheapest card that takes the trick, otherwise get rid of the weakest
+            moves.iter().find(|card| wins(card)).or(moves.first())
+        } else {
+            // the strongest card that still loses, otherwise lose as little as possible
+            moves
+                .iter()
+                .rev()
+                .find(|card| !wins(card))
+                .or(moves.first())
+        };
+
+        choice.copied().unwrap_or(Card::Jester)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{TrickEntry, Wizard, WizardState};
+    use assertor::*;
+
+    fn card_game() -> Wizard {
+        let mut wizard = Wizard::builder()
+            .players(["Ann", "Bob", "Cid"])
+            .seed(3)
+            .build()
+            .unwrap();
+        wizard
+            .apply(GameCommand::SetTrickEntry(TrickEntry::Cards))
+            .unwrap();
+        wizard
+    }
+
+    #[test]
+    fn bots_play_a_whole_game() {
+        // arrange
+        let mut wizard = card_game();
+        let mut bot = HeuristicBot;
+
+        // act
+        while wizard.state != WizardState::End {
+            let command = match wizard.current_round.as_ref() {
+                Some(round) if wizard.state == WizardState::Playing => round
+                    .players
+                    .iter()
+                    .find_map(|player| bot.command(round, player.id()))
+                    .unwrap_or(GameCommand::ScoreRound),
+                _ => GameCommand::StartNextRound,
+            };
+            wizard.apply(command).unwrap();
+        }
+
+        // assert
+        assert_that!(wizard.history().count()).is_equal_to(20);
+    }
+
+    #[test]
+    fn dealer_bot_avoids_forbidden_bid() {
+        // arrange
+        let mut wizard = card_game();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        let mut bot = HeuristicBot;
+        for _ in 0..2 {
+            let round = wizard.current_round.as_ref().unwrap();
+            let player = match round.pending_input() {
+                PendingInput::Bid { player, .. } => player,
+                _ => break,
+            };
+            wizard
+                .apply(GameCommand::SetBid { player, bid: 0 })
+                .unwrap();
+        }
+        let round = wizard.current_round.as_ref().unwrap();
+        let dealer = round.dealer().unwrap().id();
+
+        // act
+        let bid = bot.bid(round, dealer);
+
+        // assert
+        assert_that!(round.forbidden_bid()).is_equal_to(Some(1));
+        assert_that!(bid).is_equal_to(0);
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{GameCommand, GameError, GameLength, RuleSet, TrickEntry, Wizard};
+
+/// Sets up a game with all players seated, skipping the name entry of [`WizardState::Init`].
+///
+/// [`WizardState::Init`]: crate::WizardState::Init
+#[derive(Debug, Clone, Default)]
+pub struct WizardBuilder {
+    names: Vec<String>,
+    rules: RuleSet,
+    length: GameLength,
+    dealer_seat: Option<usize>,
+    round_limit: Option<usize>,
+    seed: Option<u64>,
+    trick_entry: TrickEntry,
+}
+
+impl WizardBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a player to the next free seat.
+    pub fn player(mut self, name: impl Into<String>) -> Self {
+        self.names.push(name.into());
+        self
+    }
+
+    pub fn players<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.names.extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn rules(mut self, rules: RuleSet) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    pub fn length(mut self, length: GameLength) -> Self {
+        self.length = length;
+        self
+    }
+
+    /// The seat dealing the first round, by default the last seat.
+    pub fn dealer(mut self, seat: usize) -> Self {
+        self.dealer_seat = Some(seat);
+        self
+    }
+
+    /// Ends the game after `rounds` rounds of the [`GameLength`] schedule.
+    pub fn round_limit(mut self, rounds: usize) -> Self {
+        self.round_limit = Some(rounds);
+        self
+    }
+
+    /// Shuffles every round from `seed` instead of a random one, to replay the same deals.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// How the tricks of every round are recorded, see [`GameCommand::SetTrickEntry`].
+    pub fn trick_entry(mut self, trick_entry: TrickEntry) -> Self {
+        self.trick_entry = trick_entry;
+        self
+    }
+
+    /// Validates the setup and returns a game waiting for its first round.
+    pub fn build(self) -> Result<Wizard, GameError> {
+        let mut wizard = Wizard::try_new(self.names.len(), self.rules)?;
+
+        if let Some(seat) = self.names.iter().position(String::is_empty) {
+            return Err(GameError::EmptyPlayerName { seat });
+        }
+        if let Some(seat) = self.dealer_seat {
+            if seat >= wizard.player_count {
+                return Err(GameError::InvalidDealer {
+                    seat,
+                    player_count: wizard.player_count,
+                });
+            }
+            wizard.first_dealer_seat = seat;
+        }
+        wizard.execute(GameCommand::SetGameLength(self.length))?;
+        if let Some(limit) = self.round_limit {
+            wizard.limit_rounds(limit)?;
+        }
+        wizard.seed = self.seed.or(wizard.seed);
+        wizard.execute(GameCommand::SetTrickEntry(self.trick_entry))?;
+
+        for name in self.names {
+            wizard.execute(GameCommand::AddPlayer(name))?;
+        }
+
+        Ok(wizard)
+    }
+
+    /// Like [`WizardBuilder::build`], but the first round is already being played.
+    pub fn start(self) -> Result<Wizard, GameError> {
+        let mut wizard = self.build()?;
+        wizard.execute(GameCommand::StartNextRound)?;
+
+        Ok(wizard)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{PendingInput, WizardState};
+    use assertor::*;
+
+    #[test]
+    fn build_seats_all_players() {
+        // arrange
+        let builder = WizardBuilder::new().players(["Ann", "Bob", "Cid"]);
+
+        // act
+        let wizard = builder.build().unwrap();
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::NextRound);
+        let names: Vec<&str> = wizard.players.iter().map(|player| player.name()).collect();
+        assert_that!(names).contains_exactly_in_order(vec!["Ann", "Bob", "Cid"]);
+        assert_that!(wizard.can_undo()).is_false();
+    }
+
+    #[test]
+    fn start_uses_starting_dealer() {
+        // arrange
+        let builder = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .dealer(0)
+            .round_limit(5)
+            .seed(7);
+
+        // act
+        let wizard = builder.start().unwrap();
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+        assert_that!(wizard.round_count).is_equal_to(5);
+        assert_that!(wizard.seed()).is_equal_to(Some(7));
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.dealer_seat()).is_equal_to(0);
+        assert_that!(wizard.pending_input()).is_equal_to(PendingInput::Bid {
+            player: wizard.players[1].id(),
+            range: 0..=1,
+            forbidden: None,
+        });
+    }
+
+    #[test]
+    fn build_with_too_few_players_is_rejected() {
+        // arrange
+        let builder = WizardBuilder::new().players(["Ann", "Bob"]);
+
+        // act
+        let result = builder.build();
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidPlayerCount {
+            count: 2,
+            min: 3,
+            max: 6,
+        }));
+    }
+
+    #[test]
+    fn build_with_dealer_outside_table_is_rejected() {
+        // arrange
+        let builder = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .dealer(3);
+
+        // act
+        let result = builder.build();
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidDealer {
+            seat: 3,
+            player_count: 3,
+        }));
+    }
+
+    #[test]
+    fn build_with_round_limit_beyond_deck_is_rejected() {
+        // arrange
+        let builder = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .round_limit(21);
+
+        // act
+        let result = builder.build();
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidRoundLimit { limit: 21, max: 20 }));
+    }
+
+    #[test]
+    fn start_with_game_length_deals_scheduled_cards() {
+        // arrange
+        let builder = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .length(GameLength::StartingAt(3));
+
+        // act
+        let wizard = builder.start().unwrap();
+
+        // assert
+        assert_that!(wizard.round_count).is_equal_to(18);
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.round_nr()).is_equal_to(1);
+        assert_that!(round.cards()).is_equal_to(3);
+    }
+
+    #[test]
+    fn rebuild_from_game_keeps_game_length() {
+        // arrange
+        let game = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .length(GameLength::UpAndDown)
+            .build()
+            .unwrap();
+
+        // act
+        let rematch = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .length(game.length())
+            .round_limit(game.round_count)
+            .build()
+            .unwrap();
+
+        // assert
+        assert_that!(rematch.length()).is_equal_to(GameLength::UpAndDown);
+        assert_that!(rematch.schedule()).is_equal_to(game.schedule());
+    }
+
+    #[test]
+    fn start_with_card_trick_entry_plays_cards() {
+        // arrange
+        let builder = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .trick_entry(TrickEntry::Cards);
+
+        // act
+        let wizard = builder.start().unwrap();
+
+        // assert
+        assert_that!(wizard.trick_entry()).is_equal_to(TrickEntry::Cards);
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.trick_entry()).is_equal_to(TrickEntry::Cards);
+    }
+
+    #[test]
+    fn build_with_empty_name_is_rejected() {
+        // arrange
+        let builder = WizardBuilder::new().players(["Ann", "", "Cid"]);
+
+        // act
+        let result = builder.build();
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::EmptyPlayerName { seat: 1 }));
+    }
+}
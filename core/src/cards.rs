@@ -0,0 +1,257 @@
+use crate::PlayerId;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hash::{BuildHasher, Hasher};
+
+/// Number of Wizards, and of Jesters, in one deck.
+const SPECIAL_CARDS: usize = 4;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Suit {
+    Red,
+    Yellow,
+    Green,
+    Blue,
+}
+
+impl Suit {
+    pub const ALL: [Suit; 4] = [Suit::Red, Suit::Yellow, Suit::Green, Suit::Blue];
+}
+
+/// A single card, numbered cards have a value from 1 to 13.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Card {
+    Number { suit: Suit, value: u8 },
+    Wizard,
+    Jester,
+}
+
+impl Card {
+    /// The suit of a numbered card, Wizards and Jesters have none.
+    pub fn suit(&self) -> Option<Suit> {
+        match self {
+            Card::Number { suit, .. } => Some(*suit),
+            Card::Wizard | Card::Jester => None,
+        }
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Card::Number { suit, value } => write!(f, "{:?} {}", suit, value),
+            Card::Wizard => write!(f, "Wizard"),
+            Card::Jester => write!(f, "Jester"),
+        }
+    }
+}
+
+/// The cards not dealt yet, drawn from the end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    /// An unshuffled deck of 13 cards per suit, 4 Wizards and 4 Jesters.
+    pub fn new() -> Self {
+        Self::with_decks(1)
+    }
+
+    /// `decks` standard decks put together.
+    pub fn with_decks(decks: u8) -> Self {
+        let suits = Suit::ALL
+            .into_iter()
+            .flat_map(|suit| (1..=13).map(move |value| Card::Number { suit, value }));
+        let deck = suits
+            .chain([Card::Wizard; SPECIAL_CARDS])
+            .chain([Card::Jester; SPECIAL_CARDS]);
+
+        Self {
+            cards: (0..decks).flat_map(|_| deck.clone()).collect(),
+        }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// Shuffles the deck, the same seed always gives the same order.
+    pub fn shuffle(&mut self, seed: u64) {
+        let mut rng = SplitMix64::new(seed);
+        // Fisher-Yates
+        for i in (1..self.cards.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            self.cards.swap(i, j);
+        }
+    }
+
+    /// Takes the top card, `None` once everything is dealt.
+    pub fn draw(&mut self) -> Option<Card> {
+        self.cards.pop()
+    }
+
+    /// Deals `cards` cards to every player one at a time, in the order of `players`.
+    /// Stops early if the deck runs out.
+    pub fn deal(&mut self, players: &[PlayerId], cards: u32) -> HashMap<PlayerId, Vec<Card>> {
+        let mut hands: HashMap<PlayerId, Vec<Card>> = players
+            .iter()
+            .map(|player| (*player, Vec::with_capacity(cards as usize)))
+            .collect();
+
+        for _ in 0..cards {
+            for player in players {
+                if let Some(card) = self.draw() {
+                    hands.entry(*player).or_default().push(card);
+                }
+            }
+        }
+
+        hands
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A seed that differs between calls, for games set up without one.
+pub(crate) fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// SplitMix64, small and defined bit for bit so shuffles are the same on every platform.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        ((self.next_u64() as u128 * bound as u128) >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::rules::DECK_SIZE;
+    use assertor::*;
+
+    #[test]
+    fn new_deck_has_all_cards() {
+        // arrange
+        // act
+        let deck = Deck::new();
+
+        // assert
+        let count = |card: Card| deck.cards().iter().filter(|other| **other == card).count();
+        assert_that!(deck.len()).is_equal_to(DECK_SIZE as usize);
+        assert_that!(count(Card::Wizard)).is_equal_to(4);
+        assert_that!(count(Card::Jester)).is_equal_to(4);
+        for suit in Suit::ALL {
+            let suited = deck.cards().iter().filter(|card| card.suit() == Some(suit));
+            assert_that!(suited.count()).is_equal_to(13);
+        }
+    }
+
+    #[test]
+    fn shuffle_with_same_seed_gives_same_order() {
+        // arrange
+        let mut deck = Deck::new();
+        let mut other = Deck::new();
+
+        // act
+        deck.shuffle(42);
+        other.shuffle(42);
+
+        // assert
+        assert_that!(deck).is_equal_to(other);
+        assert_that!(deck).is_not_equal_to(Deck::new());
+    }
+
+    /// Saved seeds have to keep dealing the same cards, on every platform and crate version.
+    #[test]
+    fn shuffle_order_for_a_seed_never_changes() {
+        // arrange
+        let mut deck = Deck::new();
+
+        // act
+        deck.shuffle(42);
+
+        // assert
+        let top: Vec<String> = deck
+            .cards()
+            .iter()
+            .rev()
+            .take(5)
+            .map(Card::to_string)
+            .collect();
+        assert_that!(top).contains_exactly_in_order(vec![
+            String::from("Blue 6"),
+            String::from("Red 10"),
+            String::from("Yellow 4"),
+            String::from("Yellow 7"),
+            String::from("Red 3"),
+        ]);
+    }
+
+    #[test]
+    fn shuffle_keeps_every_card() {
+        // arrange
+        let mut deck = Deck::new();
+
+        // act
+        deck.shuffle(7);
+
+        // assert
+        let mut cards = deck.cards().to_vec();
+        cards.sort();
+        let mut unshuffled = Deck::new().cards().to_vec();
+        unshuffled.sort();
+        assert_that!(cards).is_equal_to(unshuffled);
+    }
+
+    #[test]
+    fn deal_gives_every_player_the_same_number_of_cards() {
+        // arrange
+        let mut deck = Deck::new();
+        let players = [PlayerId(0), PlayerId(1), PlayerId(2)];
+
+        // act
+        let hands = deck.deal(&players, 5);
+
+        // assert
+        for player in players {
+            assert_that!(hands[&player].len()).is_equal_to(5);
+        }
+        assert_that!(deck.len()).is_equal_to(45);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{Card, GameCommand, GameLength, PlayerId, RoundState, Suit, TrickEntry, WizardState};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    InvalidPlayerCount {
+        count: usize,
+        min: usize,
+        max: usize,
+    },
+    EmptyPlayerName {
+        seat: usize,
+    },
+    InvalidDealer {
+        seat: usize,
+        player_count: usize,
+    },
+    InvalidRoundLimit {
+        limit: usize,
+        max: usize,
+    },
+    InvalidGameLength {
+        length: GameLength,
+    },
+    BidOutOfRange {
+        bid: u8,
+        max: u8,
+    },
+    ForbiddenBid {
+        player: String,
+        bid: u8,
+    },
+    DealerBidsLast {
+        player: String,
+    },
+    TrumpNotChosen {
+        player: String,
+    },
+    NoTrumpChoice,
+    CardNotInHand {
+        player: String,
+        card: Card,
+    },
+    MustFollowSuit {
+        player: String,
+        suit: Suit,
+    },
+    TricksOutOfRange {
+        tricks: u8,
+        max: u8,
+    },
+    TricksExceedRound {
+        total: u32,
+        round_nr: u32,
+        tricks: u32,
+    },
+    TricksMismatch {
+        total: u32,
+        round_nr: u32,
+        tricks: u32,
+    },
+    WrongTrickEntry {
+        trick_entry: TrickEntry,
+    },
+    InvalidState {
+        command: GameCommand,
+        state: WizardState,
+    },
+    InvalidRoundState {
+        state: RoundState,
+    },
+    MissingPlayer {
+        player: String,
+    },
+    UnknownRound {
+        round_nr: u32,
+    },
+    UnknownPlayer {
+        player: PlayerId,
+    },
+    NoCurrentRound,
+    NoPlayers,
+    NothingToUndo,
+    NothingToRedo,
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameError::InvalidPlayerCount { count, min, max } => write!(
+                f,
+                "player count must be between {} and {}, got {}",
+                min, max, count
+            ),
+            GameError::EmptyPlayerName { seat } => {
+                write!(f, "the player in seat {} has no name", seat + 1)
+            }
+            GameError::InvalidDealer { seat, player_count } => write!(
+                f,
+                "seat {} cannot deal, there are only {} seats",
+                seat + 1,
+                player_count
+            ),
+            GameError::InvalidRoundLimit { limit, max } => write!(
+                f,
+                "round limit must be between 1 and {}, got {}",
+                max, limit
+            ),
+            GameError::InvalidGameLength { length } => {
+                write!(f, "{:?} has no rounds for this many players", length)
+            }
+            GameError::BidOutOfRange { bid, max } => {
+                write!(
+                    f,
+                    "bid {} is out of range, must be between 0 and {}",
+                    bid, max
+                )
+            }
+            GameError::ForbiddenBid { player, bid } => write!(
+                f,
+                "{} may not bid {}, the bids would add up to the number of tricks",
+                player, bid
+            ),
+            GameError::DealerBidsLast { player } => {
+                write!(f, "{} deals and has to bid after everybody else", player)
+            }
+            GameError::TrumpNotChosen { player } => {
+                write!(
+                    f,
+                    "{} turned up a Wizard and has to choose trump first",
+                    player
+                )
+            }
+            GameError::NoTrumpChoice => write!(f, "trump can only be chosen after a Wizard"),
+            GameError::CardNotInHand { player, card } => {
+                write!(f, "{} does not hold {}", player, card)
+            }
+            GameError::MustFollowSuit { player, suit } => {
+                write!(f, "{} has to follow {:?}", player, suit)
+            }
+            GameError::TricksOutOfRange { tricks, max } => write!(
+                f,
+                "{} tricks are out of range, must be between 0 and {}",
+                tricks, max
+            ),
+            GameError::TricksExceedRound {
+                total,
+                round_nr,
+                tricks,
+            } => write!(
+                f,
+                "tricks total {} exceeds the {} tricks of round {}",
+                total, tricks, round_nr
+            ),
+            GameError::TricksMismatch {
+                total,
+                round_nr,
+                tricks,
+            } => write!(
+                f,
+                "tricks total {} but round {} has {} tricks",
+                total, round_nr, tricks
+            ),
+            GameError::WrongTrickEntry { trick_entry } => {
+                write!(f, "tricks of this round are recorded as {:?}", trick_entry)
+            }
+            GameError::InvalidState { command, state } => {
+                write!(f, "{:?} is not allowed in state {:?}", command, state)
+            }
+            GameError::InvalidRoundState { state } => {
+                write!(f, "not allowed while the round is in state {:?}", state)
+            }
+            GameError::MissingPlayer { player } => write!(f, "no value recorded for {}", player),
+            GameError::UnknownRound { round_nr } => {
+                write!(f, "round {} has not been scored yet", round_nr)
+            }
+            GameError::UnknownPlayer { player } => write!(f, "unknown player {:?}", player),
+            GameError::NoCurrentRound => write!(f, "no round is being played"),
+            GameError::NoPlayers => write!(f, "round has no players"),
+            GameError::NothingToUndo => write!(f, "nothing to undo"),
+            GameError::NothingToRedo => write!(f, "nothing to redo"),
+        }
+    }
+}
+
+impl Error for GameError {}
@@ -0,0 +1,106 @@
+use crate::{PlayerId, Round, ScoreBreakdown};
+
+/// A scored round as seen by a scoreboard, see [`crate::Wizard::history`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundRecord {
+    pub round_nr: u32,
+    pub cards: u32,
+    pub dealer: PlayerId,
+    /// One entry per seat, in seat order.
+    pub players: Vec<PlayerRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerRecord {
+    pub player: PlayerId,
+    pub seat: usize,
+    pub bid: u8,
+    pub tricks: u8,
+    /// Points gained or lost in this round.
+    pub delta: i16,
+    /// Points after this round.
+    pub total: i16,
+    pub made: bool,
+    pub breakdown: ScoreBreakdown,
+}
+
+impl RoundRecord {
+    /// Builds the record of `round`, `None` if the round has no dealer.
+    pub fn from_round(round: &Round) -> Option<Self> {
+        let dealer = round.dealer()?.id();
+        let players = round
+            .players
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| {
+                let breakdown = round.score_breakdown(player);
+
+                PlayerRecord {
+                    player: player.id(),
+                    seat,
+                    bid: breakdown.bid,
+                    tricks: breakdown.tricks,
+                    delta: breakdown.total(),
+                    total: player.points,
+                    made: breakdown.made(),
+                    breakdown,
+                }
+            })
+            .collect();
+
+        Some(Self {
+            round_nr: round.round_nr(),
+            cards: round.cards(),
+            dealer,
+            players,
+        })
+    }
+
+    pub fn player(&self, player: PlayerId) -> Option<&PlayerRecord> {
+        self.players.iter().find(|record| record.player == player)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GameCommand, RuleSet, Wizard};
+    use assertor::*;
+
+    #[test]
+    fn record_of_scored_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            wizard
+                .apply(GameCommand::AddPlayer(String::from(name)))
+                .unwrap();
+        }
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        for bid in [1, 0, 1] {
+            wizard.apply(GameCommand::PlaceBid(bid)).unwrap();
+        }
+        for tricks in [1, 0, 0] {
+            wizard.apply(GameCommand::ReportTricks(tricks)).unwrap();
+        }
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // act
+        let record = RoundRecord::from_round(&wizard.rounds[0]).unwrap();
+
+        // assert
+        let third = wizard.players[2].id();
+        assert_that!(record.round_nr).is_equal_to(1);
+        assert_that!(record.dealer).is_equal_to(third);
+        assert_that!(record.player(third)).is_equal_to(Some(&PlayerRecord {
+            player: third,
+            seat: 2,
+            bid: 1,
+            tricks: 0,
+            delta: -10,
+            total: -10,
+            made: false,
+            breakdown: RuleSet::official().breakdown(1, 0),
+        }));
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Which rounds are played, each round is named by the number of cards dealt in it.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameLength {
+    /// One card more every round until the deck runs out.
+    #[default]
+    Full,
+    /// Like [`GameLength::Full`], but at most the given number of rounds.
+    MaxRounds(u32),
+    /// Only rounds with an even number of cards.
+    EvenRounds,
+    /// Only rounds with an odd number of cards.
+    OddRounds,
+    /// Up to the most cards and back down to one card, e.g. 1, 2, 3, 2, 1.
+    UpAndDown,
+    /// Like [`GameLength::Full`], but starting with the given number of cards.
+    StartingAt(u32),
+}
+
+impl GameLength {
+    /// The number of cards dealt in every round, when each player can get at most `max_cards`.
+    /// Empty if the game length does not fit.
+    pub fn schedule(&self, max_cards: u32) -> Vec<u32> {
+        match *self {
+            GameLength::Full => (1..=max_cards).collect(),
+            GameLength::MaxRounds(rounds) => (1..=max_cards.min(rounds)).collect(),
+            GameLength::EvenRounds => (2..=max_cards).step_by(2).collect(),
+            GameLength::OddRounds => (1..=max_cards).step_by(2).collect(),
+            GameLength::UpAndDown => (1..=max_cards).chain((1..max_cards).rev()).collect(),
+            GameLength::StartingAt(cards) => {
+                (cards.max(1)..=max_cards).filter(|_| cards > 0).collect()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assertor::*;
+
+    #[test]
+    fn up_and_down_schedule() {
+        // arrange
+        let length = GameLength::UpAndDown;
+
+        // act
+        let schedule = length.schedule(3);
+
+        // assert
+        assert_that!(schedule).contains_exactly_in_order(vec![1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn even_rounds_schedule() {
+        // arrange
+        let length = GameLength::EvenRounds;
+
+        // act
+        let schedule = length.schedule(7);
+
+        // assert
+        assert_that!(schedule).contains_exactly_in_order(vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn starting_round_beyond_deck_has_no_rounds() {
+        // arrange
+        let length = GameLength::StartingAt(21);
+
+        // act
+        let schedule = length.schedule(20);
+
+        // assert
+        assert_that!(schedule.is_empty()).is_true();
+    }
+}
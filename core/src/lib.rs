@@ -1,37 +1,104 @@
+mod bots;
+mod builder;
+mod cards;
+mod error;
+mod history;
+mod length;
+mod rules;
+#[cfg(feature = "serde")]
+pub mod save;
+mod score;
+mod standings;
+mod trick;
+
+pub use bots::{HeuristicBot, PlayerStrategy};
+pub use builder::WizardBuilder;
+pub use cards::{Card, Deck, Suit};
+pub use error::GameError;
+pub use history::{PlayerRecord, RoundRecord};
+pub use length::GameLength;
+pub use rules::{RuleSet, DECK_SIZE};
+pub use score::{ScoreBreakdown, ScorePart, ScoreRule};
+pub use standings::{Standing, TieBreaker};
+pub use trick::Trick;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+/// Fewest players any [`RuleSet`] preset supports.
+pub const MIN_PLAYERS: usize = 2;
+/// Most players any [`RuleSet`] preset supports.
+pub const MAX_PLAYERS: usize = 12;
+
+/// Identifies a player for the whole game, independent of their name.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerId(u32);
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Player {
+    id: PlayerId,
     name: String,
     pub points: i16,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Tips {
-    tips: HashMap<String, u8>,
+    tips: HashMap<PlayerId, u8>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum RoundState {
     Tipping,
-    Retipping,
     Playing,
     Checking,
     End,
 }
 
+/// How the tricks of a round are recorded.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TrickEntry {
+    /// Every player reports how many tricks they took once the round is over.
+    #[default]
+    Totals,
+    /// The winner of every trick is recorded as it happens.
+    Winners,
+    /// The cards are played in the game and every trick is won by the rules.
+    Cards,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Round {
     round_nr: u32,
+    cards: u32,
     pub state: RoundState,
     pub tips: Tips,
     pub matches: Tips,
+    /// The players in seat order, which stays the same for the whole game.
     pub players: Vec<Player>,
-    current_player_index: usize,
+    dealer_seat: usize,
+    first_bidder_seat: usize,
+    trick_entry: TrickEntry,
+    rules: RuleSet,
+    /// The cards each player holds, empty until the round is dealt.
+    hands: HashMap<PlayerId, Vec<Card>>,
+    /// The card turned up after dealing, `None` if the whole deck was dealt.
+    trump_card: Option<Card>,
+    trump: Option<Suit>,
+    /// The tricks played with [`TrickEntry::Cards`], the last one may be incomplete.
+    tricks: Vec<Trick>,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum WizardState {
     Init,
     NextRound,
@@ -40,21 +107,136 @@ pub enum WizardState {
     End,
 }
 
+/// Input pushed into the game by a frontend, see [`Wizard::apply`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GameCommand {
+    AddPlayer(String),
+    PlaceBid(u8),
+    ReportTricks(u8),
+    SetBid {
+        player: PlayerId,
+        bid: u8,
+    },
+    SetTricks {
+        player: PlayerId,
+        tricks: u8,
+    },
+    SubmitBids(Tips),
+    SubmitTricks(Tips),
+    ScoreRound,
+    StartNextRound,
+    AmendRound {
+        round_nr: u32,
+        tips: Tips,
+        matches: Tips,
+    },
+    RenamePlayer {
+        player: PlayerId,
+        name: String,
+    },
+    RecordTrickWinner(PlayerId),
+    /// The dealer picks trump after turning up a Wizard.
+    ChooseTrump(Suit),
+    /// The player whose turn it is plays a card, see [`TrickEntry::Cards`].
+    PlayCard(Card),
+    SetTrickEntry(TrickEntry),
+    SetGameLength(GameLength),
+    SetSeed(u64),
+}
+
+/// Something that happened while applying a [`GameCommand`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    PlayerAdded { player: PlayerId, name: String },
+    PlayerRenamed { player: PlayerId, name: String },
+    RoundStarted { round_nr: u32 },
+    BidPlaced { player: PlayerId, bid: u8 },
+    BiddingComplete,
+    TricksReported { player: PlayerId, tricks: u8 },
+    TrumpChosen { player: PlayerId, suit: Suit },
+    CardPlayed { player: PlayerId, card: Card },
+    TrickWon { player: PlayerId, trick: u8 },
+    TricksComplete,
+    RoundScored { round_nr: u32 },
+    RoundAmended { round_nr: u32 },
+    GameEnded,
+}
+
+/// The value a frontend has to ask for next, see [`Wizard::pending_input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PendingInput {
+    PlayerName {
+        seat: usize,
+    },
+    Bid {
+        player: PlayerId,
+        range: RangeInclusive<u8>,
+        forbidden: Option<u8>,
+    },
+    Tricks {
+        player: PlayerId,
+        range: RangeInclusive<u8>,
+    },
+    /// The winner of trick number `trick` out of `of`.
+    TrickWinner {
+        trick: u8,
+        of: u8,
+    },
+    /// The dealer turned up a Wizard and has to choose trump before bidding starts.
+    Trump {
+        player: PlayerId,
+    },
+    Card {
+        player: PlayerId,
+    },
+    Nothing,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Wizard {
     pub state: WizardState,
     pub round_count: usize,
     pub round_index: usize,
+    length: GameLength,
+    /// Cards dealt in every round, see [`GameLength::schedule`].
+    schedule: Vec<u32>,
     pub player_count: usize,
     player_index: usize,
     pub players: Vec<Player>,
     pub rounds: Vec<Round>,
     pub current_round: Option<Round>,
+    rules: RuleSet,
+    trick_entry: TrickEntry,
+    first_dealer_seat: usize,
+    seed: Option<u64>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    history: History,
+}
+
+/// Snapshots taken before every applied command, see [`Wizard::undo`] and [`Wizard::redo`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct History {
+    undo: Vec<Wizard>,
+    redo: Vec<Wizard>,
 }
 
 impl Player {
-    pub fn new(name: String) -> Self {
-        Self { name, points: 0 }
+    pub fn new(id: PlayerId, name: String) -> Self {
+        Self {
+            id,
+            name,
+            points: 0,
+        }
+    }
+
+    pub fn id(&self) -> PlayerId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
     }
 }
 
@@ -66,6 +248,20 @@ impl Display for Player {
     }
 }
 
+impl Default for Tips {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromIterator<(PlayerId, u8)> for Tips {
+    fn from_iter<T: IntoIterator<Item = (PlayerId, u8)>>(iter: T) -> Self {
+        Self {
+            tips: iter.into_iter().collect(),
+        }
+    }
+}
+
 impl Tips {
     pub fn new() -> Self {
         Self {
@@ -74,334 +270,2323 @@ impl Tips {
     }
 
     pub fn add_tip(&mut self, player: &Player, tip: u8) {
-        self.tips.insert(player.name.to_string(), tip);
+        self.tips.insert(player.id, tip);
     }
 
     pub fn get_tip(&self, player: &Player) -> u8 {
-        self.tips.get(&player.name).copied().unwrap_or(0)
+        self.get(player).unwrap_or(0)
+    }
+
+    pub fn get(&self, player: &Player) -> Option<u8> {
+        self.get_by_id(player.id)
+    }
+
+    pub fn get_by_id(&self, player: PlayerId) -> Option<u8> {
+        self.tips.get(&player).copied()
     }
 
     pub fn sum(&self) -> u32 {
         self.tips.values().copied().map(u32::from).sum()
     }
+
+    /// The recorded values in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (PlayerId, u8)> + '_ {
+        self.tips.iter().map(|(player, tip)| (*player, *tip))
+    }
+
+    pub fn len(&self) -> usize {
+        self.tips.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tips.is_empty()
+    }
 }
 
 impl Round {
-    pub fn new(round_nr: u32, players: Vec<Player>) -> Self {
+    /// Creates a round in which every player gets `cards` cards, dealt by the player in
+    /// `dealer_seat`. The player to their left bids first.
+    pub fn new(
+        round_nr: u32,
+        cards: u32,
+        players: Vec<Player>,
+        dealer_seat: usize,
+        rules: RuleSet,
+    ) -> Self {
+        let first_bidder_seat = (dealer_seat + 1) % players.len().max(1);
+
         Self {
             round_nr,
+            cards,
             state: RoundState::Tipping,
             tips: Tips::new(),
             matches: Tips::new(),
             players,
-            current_player_index: 0,
+            dealer_seat,
+            first_bidder_seat,
+            trick_entry: TrickEntry::default(),
+            rules,
+            hands: HashMap::new(),
+            trump_card: None,
+            trump: None,
+            tricks: Vec::new(),
         }
     }
 
-    pub fn play(&mut self, input_callback: impl Fn(&Player, &RoundState) -> u8) {
-        if self.state == RoundState::Tipping || self.state == RoundState::Retipping {
-            let current_player = &self.players[self.current_player_index];
-            self.tips
-                .add_tip(current_player, input_callback(current_player, &self.state));
+    /// Shuffles the decks of the rules with `seed`, deals every player their hand starting
+    /// with the first bidder and turns up the next card for trump. A Jester means no trump,
+    /// a Wizard lets the dealer choose and there is no trump if no card is left, as in the
+    /// final round of a full game.
+    pub fn deal(&mut self, seed: u64) {
+        let mut deck = Deck::with_decks(self.rules.decks);
+        deck.shuffle(seed);
 
-            if self.current_player_index + 1 == self.players.len() {
-                let sum_of_tips = self.tips.sum();
-                self.state = if sum_of_tips == self.round_nr {
-                    RoundState::Retipping
-                } else {
-                    RoundState::Playing
-                };
+        let players: Vec<PlayerId> = self.bidding_order().map(Player::id).collect();
+        self.hands = deck.deal(&players, self.cards);
+        self.trump_card = deck.draw();
+        self.trump = self.trump_card.and_then(|card| card.suit());
+        self.tricks.clear();
+    }
 
-                self.current_player_index = 0;
-            } else {
-                self.current_player_index += 1;
-            }
-        } else if self.state == RoundState::Playing {
-            let current_player = &self.players[self.current_player_index];
-            self.matches
-                .add_tip(current_player, input_callback(current_player, &self.state));
-
-            if self.current_player_index == self.players.len() - 1 {
-                let sum_of_matches = self.matches.sum();
-                if sum_of_matches == self.round_nr {
-                    self.state = RoundState::Checking;
-                }
+    pub fn trump_card(&self) -> Option<Card> {
+        self.trump_card
+    }
 
-                self.current_player_index = 0;
-            } else {
-                self.current_player_index += 1;
-            }
-        } else if self.state == RoundState::Checking {
-            for player in self.players.iter_mut() {
-                let tip = self.tips.get_tip(player);
-                let matched = self.matches.get_tip(player);
-                let diff = u8::abs_diff(tip, matched);
-
-                if diff == 0 {
-                    player.points += 20 + i16::from(tip) * 10
-                } else {
-                    player.points -= i16::from(diff) * 10;
-                };
+    /// The trump suit, `None` for no trump or while the dealer still has to choose.
+    pub fn trump(&self) -> Option<Suit> {
+        self.trump
+    }
+
+    pub fn tricks(&self) -> &[Trick] {
+        &self.tricks
+    }
+
+    fn needs_trump_choice(&self) -> bool {
+        self.trump_card == Some(Card::Wizard) && self.trump.is_none()
+    }
+
+    /// Lets the dealer pick trump after a Wizard was turned up.
+    pub fn choose_trump(&mut self, suit: Suit) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Tipping {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
+        if !self.needs_trump_choice() {
+            return Err(GameError::NoTrumpChoice);
+        }
+
+        self.trump = Some(suit);
+        let player = self.dealer().ok_or(GameError::NoPlayers)?.id;
+
+        Ok(vec![GameEvent::TrumpChosen { player, suit }])
+    }
+
+    /// The player who plays the next card: the first bidder leads the first trick and the
+    /// winner of a trick leads the next one.
+    fn next_to_play(&self) -> Option<PlayerId> {
+        if self.state != RoundState::Playing || self.trick_entry != TrickEntry::Cards {
+            return None;
+        }
+
+        match self.tricks.last() {
+            None => self.player_at(self.first_bidder_seat).map(Player::id),
+            Some(trick) if trick.len() == self.players.len() => trick.winner(),
+            Some(trick) => {
+                let (last, _) = trick.plays().last()?;
+                let seat = self.players.iter().position(|player| player.id == *last)?;
+                self.player_at((seat + 1) % self.players.len())
+                    .map(Player::id)
             }
+        }
+    }
 
-            self.state = RoundState::End;
+    /// The trick the next card is played to, a new one once the last trick is complete.
+    fn open_trick(&self) -> Trick {
+        match self.tricks.last() {
+            Some(trick) if trick.len() < self.players.len() => trick.clone(),
+            _ => Trick::new(self.trump),
         }
     }
-}
 
-impl Display for Round {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for player in self.players.iter() {
-            writeln!(f, "{}", player)?;
+    /// The cards of the player's hand they may play to the current trick, see
+    /// [`Trick::legal_moves`]. Empty if the round was not dealt.
+    pub fn legal_moves(&self, player: PlayerId) -> Vec<Card> {
+        let hand = self.hand(player).unwrap_or_default();
+        self.open_trick().legal_moves(hand)
+    }
+
+    /// Plays a card for the player whose turn it is. A completed trick is counted for its
+    /// winner like with [`Round::record_trick_winner`].
+    pub fn play_card(&mut self, card: Card) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Playing || self.trick_entry != TrickEntry::Cards {
+            return Err(GameError::InvalidRoundState { state: self.state });
         }
 
-        write!(f, "{:?}", self.state)?;
+        let player = self
+            .next_to_play()
+            .ok_or(GameError::InvalidRoundState { state: self.state })?;
+        let name = || self.player(player).map(|player| player.name.to_string());
+        let hand = self.hand(player).unwrap_or_default();
+        if !hand.contains(&card) {
+            return Err(GameError::CardNotInHand {
+                player: name()?,
+                card,
+            });
+        }
+        let mut trick = self.open_trick();
+        if let Some(suit) = trick.led_suit() {
+            if !trick.legal_moves(hand).contains(&card) {
+                return Err(GameError::MustFollowSuit {
+                    player: name()?,
+                    suit,
+                });
+            }
+        }
 
-        Ok(())
+        let hand = self.hands.entry(player).or_default();
+        if let Some(position) = hand.iter().position(|other| *other == card) {
+            hand.remove(position);
+        }
+
+        let players = self.players.len();
+        trick.play(player, card);
+        let winner = trick.winner().filter(|_| trick.len() == players);
+        // the open trick replaces the unfinished one it was copied from
+        if self.tricks.last().is_some_and(|last| last.len() < players) {
+            self.tricks.pop();
+        }
+        self.tricks.push(trick);
+
+        let mut events = vec![GameEvent::CardPlayed { player, card }];
+        if let Some(winner) = winner {
+            events.extend(self.win_trick(winner)?);
+        }
+
+        Ok(events)
     }
-}
 
-impl Wizard {
-    pub fn new(player_count: usize) -> Self {
-        Self {
-            state: WizardState::Init,
-            round_count: 60 / player_count,
-            round_index: 0,
-            player_count,
-            player_index: 0,
-            players: Vec::with_capacity(player_count),
-            rounds: Vec::with_capacity(60 / player_count),
-            current_round: None,
+    /// The cards the player holds, `None` if the round was not dealt.
+    pub fn hand(&self, player: PlayerId) -> Option<&[Card]> {
+        self.hands.get(&player).map(Vec::as_slice)
+    }
+
+    pub fn round_nr(&self) -> u32 {
+        self.round_nr
+    }
+
+    /// Cards dealt to every player, which is also the number of tricks.
+    pub fn cards(&self) -> u32 {
+        self.cards
+    }
+
+    pub fn dealer_seat(&self) -> usize {
+        self.dealer_seat
+    }
+
+    pub fn first_bidder_seat(&self) -> usize {
+        self.first_bidder_seat
+    }
+
+    pub fn player_at(&self, seat: usize) -> Option<&Player> {
+        self.players.get(seat)
+    }
+
+    /// The players in the order they bid and report their tricks, starting with the first bidder.
+    pub fn bidding_order(&self) -> impl Iterator<Item = &Player> {
+        (0..self.players.len()).filter_map(|turn| self.player_at(self.seat_of_turn(turn)))
+    }
+
+    fn seat_of_turn(&self, turn: usize) -> usize {
+        (self.first_bidder_seat + turn) % self.players.len().max(1)
+    }
+
+    /// Position of the player in [`Round::bidding_order`].
+    fn turn_of(&self, id: PlayerId) -> Option<usize> {
+        (0..self.players.len()).find(|turn| self.players[self.seat_of_turn(*turn)].id == id)
+    }
+
+    fn player(&self, id: PlayerId) -> Result<&Player, GameError> {
+        self.players
+            .iter()
+            .find(|player| player.id == id)
+            .ok_or(GameError::UnknownPlayer { player: id })
+    }
+
+    fn is_dealer(&self, player: &Player) -> bool {
+        self.dealer().map(Player::id) == Some(player.id)
+    }
+
+    /// The bids while bidding and the tricks while playing.
+    fn entries(&self) -> Option<&Tips> {
+        match self.state {
+            RoundState::Tipping => Some(&self.tips),
+            RoundState::Playing => Some(&self.matches),
+            RoundState::Checking | RoundState::End => None,
         }
     }
 
-    pub fn play(
-        &mut self,
-        player_callback: impl Fn(usize) -> String,
-        input_callback: impl Fn(&Player, &RoundState) -> u8,
-    ) {
-        if self.state == WizardState::Init {
-            let player = Player::new(player_callback(self.player_index));
-            self.players.insert(self.player_index, player);
-
-            if self.player_index + 1 == self.player_count {
-                self.state = WizardState::NextRound;
-                self.player_index = 0;
-            } else {
-                self.player_index += 1;
-            }
-        } else if self.state == WizardState::Playing {
-            let current_round = self.current_round.as_mut().unwrap();
+    fn is_complete(&self, entries: &Tips) -> bool {
+        self.players
+            .iter()
+            .all(|player| entries.get(player).is_some())
+    }
 
-            if current_round.state == RoundState::End {
-                self.state = WizardState::EndRound;
-            }
+    /// The first player in bidding order whose bid or tricks are still missing.
+    fn current_player(&self) -> Option<&Player> {
+        let entries = self.entries()?;
+        self.bidding_order()
+            .find(|player| entries.get(player).is_none())
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
 
-            current_round.play(input_callback); // TODO: event/callback
+    pub fn trick_entry(&self) -> TrickEntry {
+        self.trick_entry
+    }
 
-            if self.round_index == self.round_count && current_round.state == RoundState::End {
-                self.state = WizardState::End;
+    pub fn pending_input(&self) -> PendingInput {
+        let max = self.cards as u8;
+        if self.trick_entry == TrickEntry::Cards {
+            if self.state == RoundState::Tipping && self.needs_trump_choice() {
+                if let Some(dealer) = self.dealer() {
+                    return PendingInput::Trump { player: dealer.id };
+                }
+            }
+            if let Some(player) = self.next_to_play() {
+                return PendingInput::Card { player };
             }
-        } else if self.state == WizardState::NextRound {
-            self.round_index += 1;
-            self.current_round =
-                Option::from(Round::new(self.round_index as u32, self.players.to_vec()));
-
-            self.state = WizardState::Playing;
-        } else if self.state == WizardState::EndRound {
-            let current_round = self.current_round.as_mut().unwrap();
-            self.rounds
-                .insert(self.round_index - 1, current_round.to_owned());
-            self.players = current_round.players.to_vec();
-            self.players.rotate_left(1);
+        }
+        if self.state == RoundState::Playing && self.trick_entry == TrickEntry::Winners {
+            return PendingInput::TrickWinner {
+                trick: self.matches.sum() as u8 + 1,
+                of: max,
+            };
+        }
 
-            self.state = WizardState::NextRound;
+        let current_player = match self.current_player() {
+            Some(player) => player,
+            None => return PendingInput::Nothing,
+        };
+
+        match self.state {
+            RoundState::Tipping => PendingInput::Bid {
+                player: current_player.id,
+                range: 0..=max,
+                forbidden: if self.is_dealer(current_player) {
+                    self.forbidden_bid()
+                } else {
+                    None
+                },
+            },
+            RoundState::Playing => PendingInput::Tricks {
+                player: current_player.id,
+                range: 0..=max,
+            },
+            RoundState::Checking | RoundState::End => PendingInput::Nothing,
         }
     }
-}
 
-impl Display for Wizard {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for player in &self.players {
-            write!(f, "{} ", player)?;
+    /// The dealer deals the cards and bids last.
+    pub fn dealer(&self) -> Option<&Player> {
+        self.player_at(self.dealer_seat)
+    }
+
+    /// The bid the dealer may not place because all bids would add up to the number of
+    /// tricks, known once everybody else has bid.
+    pub fn forbidden_bid(&self) -> Option<u8> {
+        if !self.rules.bid_sum_restriction {
+            return None;
         }
 
-        Ok(())
+        let others = self.players.iter().filter(|player| !self.is_dealer(player));
+        let mut sum_of_others = 0;
+        for player in others {
+            sum_of_others += u32::from(self.tips.get(player)?);
+        }
+
+        self.cards
+            .checked_sub(sum_of_others)
+            .map(|forbidden| forbidden as u8)
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use assertor::*;
+    /// Checks whether the player may bid `bid`, without placing it. With the bid sum
+    /// restriction the dealer has to wait for everybody else.
+    pub fn validate_bid(&self, player: PlayerId, bid: u8) -> Result<(), GameError> {
+        let max = self.cards as u8;
+        if bid > max {
+            return Err(GameError::BidOutOfRange { bid, max });
+        }
 
-    fn player_input(index: usize) -> String {
-        let names = vec!["Player 1", "Player 2", "Player 3"];
+        let player = self.player(player)?;
+        if self.trick_entry == TrickEntry::Cards && self.needs_trump_choice() {
+            return Err(GameError::TrumpNotChosen {
+                player: self
+                    .dealer()
+                    .map(|dealer| dealer.name.to_string())
+                    .unwrap_or_default(),
+            });
+        }
+        if self.is_dealer(player) && self.rules.bid_sum_restriction {
+            let others_missing = self
+                .players
+                .iter()
+                .any(|other| other.id != player.id && self.tips.get(other).is_none());
+            if others_missing {
+                return Err(GameError::DealerBidsLast {
+                    player: player.name.to_string(),
+                });
+            }
+            if self.forbidden_bid() == Some(bid) {
+                return Err(GameError::ForbiddenBid {
+                    player: player.name.to_string(),
+                    bid,
+                });
+            }
+        }
 
-        return String::from(names[index]);
+        Ok(())
     }
 
-    fn empty_round_input(_player: &Player, _state: &RoundState) -> u8 {
-        return 0;
+    /// Checks whether the player may report `tricks`: the count has to fit into the round,
+    /// all reported tricks may not exceed the round number and must add up to it once the
+    /// last player reports.
+    pub fn validate_tricks(&self, player: PlayerId, tricks: u8) -> Result<(), GameError> {
+        let max = self.cards as u8;
+        if tricks > max {
+            return Err(GameError::TricksOutOfRange { tricks, max });
+        }
+
+        let player = self.player(player)?;
+        let previous = u32::from(self.matches.get_tip(player));
+        let total = self.matches.sum() - previous + u32::from(tricks);
+        if total > self.cards {
+            return Err(GameError::TricksExceedRound {
+                total,
+                round_nr: self.round_nr,
+                tricks: self.cards,
+            });
+        }
+
+        let others_reported = self
+            .players
+            .iter()
+            .all(|other| other.id == player.id || self.matches.get(other).is_some());
+        if others_reported && total != self.cards {
+            return Err(GameError::TricksMismatch {
+                total,
+                round_nr: self.round_nr,
+                tricks: self.cards,
+            });
+        }
+
+        Ok(())
     }
 
-    #[test]
-    fn initialization_no_state_change_state_is_init() {
-        // arrange
-        // act
-        let wizard = Wizard::new(3);
+    /// Places the bid of the player whose turn it is.
+    pub fn place_bid(&mut self, bid: u8) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Tipping {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
 
-        // assert
-        assert_that!(wizard.state).is_equal_to(WizardState::Init);
+        let player = self.current_player().ok_or(GameError::NoPlayers)?.id;
+        self.set_bid(player, bid)
     }
 
-    #[test]
-    fn initialization_input_names_state_is_next_round() {
-        // arrange
-        let mut wizard = Wizard::new(3);
+    /// Places or changes the bid of any player, bidding ends once everybody has bid.
+    pub fn set_bid(&mut self, player: PlayerId, bid: u8) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Tipping {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
 
-        // act
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
+        self.validate_bid(player, bid)?;
+        let player = self.player(player)?.clone();
+        self.tips.add_tip(&player, bid);
 
-        // assert
-        assert_that!(wizard.state).is_equal_to(WizardState::NextRound);
-        assert_that!(wizard.players[0].name).is_equal_to(String::from("Player 1"));
-        assert_that!(wizard.players[1].name).is_equal_to(String::from("Player 2"));
-        assert_that!(wizard.players[2].name).is_equal_to(String::from("Player 3"));
-    }
+        let mut events = vec![GameEvent::BidPlaced {
+            player: player.id,
+            bid,
+        }];
 
-    #[test]
-    fn initialization_input_names_state_is_playing() {
-        // arrange
-        let mut wizard = Wizard::new(3);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
+        if self.is_complete(&self.tips) {
+            self.state = RoundState::Playing;
+            events.push(GameEvent::BiddingComplete);
+        }
 
-        // act
-        wizard.play(player_input, empty_round_input);
+        Ok(events)
+    }
 
-        // assert
-        assert_that!(wizard.current_round).is_some();
-        let round = wizard.current_round.unwrap();
-        assert_that!(round.state).is_equal_to(RoundState::Tipping);
+    /// Places several bids at once in bidding order, so the dealer's bid comes last. Either
+    /// all bids are placed or none.
+    pub fn submit_bids(&mut self, bids: Tips) -> Result<Vec<GameEvent>, GameError> {
+        let mut round = self.clone();
+        let mut events = vec![];
+        for (player, bid) in self.in_bidding_order(&bids) {
+            events.extend(round.set_bid(player, bid)?);
+        }
 
-        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+        *self = round;
+        Ok(events)
     }
 
-    #[test]
-    fn tip_first_round() {
-        // arrange
-        let mut wizard = Wizard::new(3);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
+    /// Fails unless the tricks of this round are recorded with `trick_entry`, so totals and
+    /// trick winners are never mixed.
+    fn expect_trick_entry(&self, trick_entry: TrickEntry) -> Result<(), GameError> {
+        if self.trick_entry != trick_entry {
+            return Err(GameError::WrongTrickEntry {
+                trick_entry: self.trick_entry,
+            });
+        }
 
-        // act
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
+        Ok(())
+    }
 
-        // assert
-        assert_that!(wizard.current_round).is_some();
-        let round = wizard.current_round.unwrap();
-        assert_that!(round.state).is_equal_to(RoundState::Playing);
+    /// Reports the tricks of the player whose turn it is.
+    pub fn report_tricks(&mut self, tricks: u8) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Playing {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
+        self.expect_trick_entry(TrickEntry::Totals)?;
 
-        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+        let player = self.current_player().ok_or(GameError::NoPlayers)?.id;
+        self.set_tricks(player, tricks)
     }
 
-    #[test]
-    fn play_first_round() {
-        // arrange
-        let mut wizard = Wizard::new(3);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
+    /// Reports or changes the tricks of any player, the round is ready to be scored once
+    /// everybody has reported.
+    pub fn set_tricks(
+        &mut self,
+        player: PlayerId,
+        tricks: u8,
+    ) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Playing {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
+        self.expect_trick_entry(TrickEntry::Totals)?;
 
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
+        self.validate_tricks(player, tricks)?;
+        let player = self.player(player)?.clone();
+        self.matches.add_tip(&player, tricks);
 
-        // act
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
+        let mut events = vec![GameEvent::TricksReported {
+            player: player.id,
+            tricks,
+        }];
 
-        // assert
-        assert_that!(wizard.current_round).is_some();
-        let round = wizard.current_round.unwrap();
-        assert_that!(round.state).is_equal_to(RoundState::Checking);
+        if self.is_complete(&self.matches) {
+            self.state = RoundState::Checking;
+            events.push(GameEvent::TricksComplete);
+        }
 
-        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+        Ok(events)
     }
 
-    #[test]
-    fn check_first_round() {
-        // arrange
-        let mut wizard = Wizard::new(3);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
-        wizard.play(player_input, empty_round_input);
+    /// Reports the tricks of several players at once, either all of them or none.
+    pub fn submit_tricks(&mut self, tricks: Tips) -> Result<Vec<GameEvent>, GameError> {
+        let mut round = self.clone();
+        let mut events = vec![];
+        for (player, tricks) in self.in_bidding_order(&tricks) {
+            events.extend(round.set_tricks(player, tricks)?);
+        }
 
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
+        *self = round;
+        Ok(events)
+    }
 
-        wizard.play(player_input, |_, _| {
-            return 1;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
-        wizard.play(player_input, |_, _| {
-            return 0;
-        });
+    /// Records who won the next trick. Once all tricks are taken, players who did not win any
+    /// get zero tricks and the round is ready to be scored.
+    pub fn record_trick_winner(&mut self, player: PlayerId) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Playing {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
+        self.expect_trick_entry(TrickEntry::Winners)?;
 
-        // act
-        wizard.play(player_input, empty_round_input); // check round
-        wizard.play(player_input, empty_round_input); // end round
-        wizard.play(player_input, empty_round_input); // next round
+        self.win_trick(player)
+    }
 
-        // assert
-        assert_that!(wizard.current_round).is_some();
-        let round = wizard.current_round.unwrap();
-        assert_that!(round.state).is_equal_to(RoundState::End);
+    /// Counts the next trick for `player`, for recorded winners and played cards alike.
+    fn win_trick(&mut self, player: PlayerId) -> Result<Vec<GameEvent>, GameError> {
+        let trick = self.matches.sum() + 1;
+        if trick > self.cards {
+            return Err(GameError::TricksExceedRound {
+                total: trick,
+                round_nr: self.round_nr,
+                tricks: self.cards,
+            });
+        }
 
-        assert_that!(wizard.state).is_equal_to(WizardState::NextRound);
+        let winner = self.player(player)?.clone();
+        self.matches
+            .add_tip(&winner, self.matches.get_tip(&winner) + 1);
+
+        let mut events = vec![GameEvent::TrickWon {
+            player,
+            trick: trick as u8,
+        }];
+
+        if trick == self.cards {
+            for player in self.players.iter() {
+                if self.matches.get(player).is_none() {
+                    self.matches.add_tip(player, 0);
+                }
+            }
+            self.state = RoundState::Checking;
+            events.push(GameEvent::TricksComplete);
+        }
+
+        Ok(events)
+    }
+
+    /// Unknown players are sorted last, so they are reported after all valid entries.
+    fn in_bidding_order(&self, entries: &Tips) -> Vec<(PlayerId, u8)> {
+        let mut entries: Vec<(PlayerId, u8)> = entries.iter().collect();
+        entries.sort_by_key(|(player, _)| (self.turn_of(*player).unwrap_or(usize::MAX), *player));
+        entries
+    }
+
+    pub fn score(&mut self) -> Result<Vec<GameEvent>, GameError> {
+        if self.state != RoundState::Checking {
+            return Err(GameError::InvalidRoundState { state: self.state });
+        }
 
-        let mut players = wizard.players.to_vec();
-        players.rotate_right(1); // we need to rotate back one because end round already rotates players
-        assert_that!(players[0].points).is_equal_to(30);
-        assert_that!(players[1].points).is_equal_to(20);
-        assert_that!(players[2].points).is_equal_to(-10);
+        let deltas = self.points_deltas();
+        for (player, delta) in self.players.iter_mut().zip(deltas) {
+            player.points += delta;
+        }
+
+        self.state = RoundState::End;
+
+        Ok(vec![GameEvent::RoundScored {
+            round_nr: self.round_nr,
+        }])
+    }
+
+    /// Points the player gains or loses in this round, derived from the recorded tips and matches.
+    pub fn points_delta(&self, player: &Player) -> i16 {
+        self.score_breakdown(player).total()
+    }
+
+    /// Explains [`Round::points_delta`] rule by rule.
+    pub fn score_breakdown(&self, player: &Player) -> ScoreBreakdown {
+        self.rules
+            .breakdown(self.tips.get_tip(player), self.matches.get_tip(player))
+    }
+
+    fn points_deltas(&self) -> Vec<i16> {
+        self.players
+            .iter()
+            .map(|player| self.points_delta(player))
+            .collect()
+    }
+
+    fn validate_results(&self, tips: &Tips, matches: &Tips) -> Result<(), GameError> {
+        let max = self.cards as u8;
+
+        for player in self.players.iter() {
+            let tip = tips.get(player).ok_or_else(|| GameError::MissingPlayer {
+                player: player.name.to_string(),
+            })?;
+            if tip > max {
+                return Err(GameError::BidOutOfRange { bid: tip, max });
+            }
+
+            let matched = matches
+                .get(player)
+                .ok_or_else(|| GameError::MissingPlayer {
+                    player: player.name.to_string(),
+                })?;
+            if matched > max {
+                return Err(GameError::TricksOutOfRange {
+                    tricks: matched,
+                    max,
+                });
+            }
+        }
+
+        let total = matches.sum();
+        if total != self.cards {
+            return Err(GameError::TricksMismatch {
+                total,
+                round_nr: self.round_nr,
+                tricks: self.cards,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for player in self.players.iter() {
+            writeln!(f, "{}", player)?;
+        }
+
+        write!(f, "{:?}", self.state)?;
+
+        Ok(())
+    }
+}
+
+impl Wizard {
+    /// Creates a new game, see [`Wizard::try_new`] for a non-panicking variant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rules` are not meant for `player_count` players, see [`RuleSet::player_counts`].
+    pub fn new(player_count: usize, rules: RuleSet) -> Self {
+        match Self::try_new(player_count, rules) {
+            Ok(wizard) => wizard,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Starts setting up a game with known players, see [`WizardBuilder`].
+    pub fn builder() -> WizardBuilder {
+        WizardBuilder::new()
+    }
+
+    pub fn try_new(player_count: usize, rules: RuleSet) -> Result<Self, GameError> {
+        let player_counts = rules.player_counts();
+        if !player_counts.contains(&player_count) {
+            return Err(GameError::InvalidPlayerCount {
+                count: player_count,
+                min: *player_counts.start(),
+                max: *player_counts.end(),
+            });
+        }
+
+        let schedule = GameLength::Full.schedule(rules.max_cards(player_count));
+        Ok(Self {
+            state: WizardState::Init,
+            round_count: schedule.len(),
+            round_index: 0,
+            length: GameLength::Full,
+            player_count,
+            player_index: 0,
+            players: Vec::with_capacity(player_count),
+            rounds: Vec::with_capacity(schedule.len()),
+            schedule,
+            current_round: None,
+            rules,
+            trick_entry: TrickEntry::default(),
+            first_dealer_seat: player_count - 1,
+            seed: Some(cards::random_seed()),
+            history: History::default(),
+        })
+    }
+
+    pub fn apply(&mut self, command: GameCommand) -> Result<Vec<GameEvent>, GameError> {
+        let snapshot = self.snapshot();
+        let events = self.execute(command)?;
+
+        self.history.undo.push(snapshot);
+        self.history.redo.clear();
+
+        Ok(events)
+    }
+
+    /// Reverts the last applied command.
+    pub fn undo(&mut self) -> Result<(), GameError> {
+        let previous = self.history.undo.pop().ok_or(GameError::NothingToUndo)?;
+        let current = self.restore(previous);
+        self.history.redo.push(current);
+
+        Ok(())
+    }
+
+    /// Re-applies the last command reverted by [`Wizard::undo`].
+    pub fn redo(&mut self) -> Result<(), GameError> {
+        let next = self.history.redo.pop().ok_or(GameError::NothingToRedo)?;
+        let current = self.restore(next);
+        self.history.undo.push(current);
+
+        Ok(())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    fn snapshot(&mut self) -> Wizard {
+        let history = std::mem::take(&mut self.history);
+        let snapshot = self.clone();
+        self.history = history;
+
+        snapshot
+    }
+
+    fn restore(&mut self, snapshot: Wizard) -> Wizard {
+        let history = std::mem::take(&mut self.history);
+        let current = std::mem::replace(self, snapshot);
+        self.history = history;
+
+        current
+    }
+
+    fn execute(&mut self, command: GameCommand) -> Result<Vec<GameEvent>, GameError> {
+        match (self.state, command) {
+            (WizardState::Init, GameCommand::AddPlayer(name)) => Ok(self.add_player(name)),
+            (WizardState::Init | WizardState::NextRound, GameCommand::SetGameLength(length)) => {
+                self.set_game_length(length)
+            }
+            (WizardState::Init | WizardState::NextRound, GameCommand::SetSeed(seed)) => {
+                Ok(self.set_seed(seed))
+            }
+            (WizardState::NextRound | WizardState::EndRound, GameCommand::StartNextRound) => {
+                Ok(self.start_next_round())
+            }
+            (WizardState::Playing, GameCommand::PlaceBid(bid)) => {
+                self.current_round_mut()?.place_bid(bid)
+            }
+            (WizardState::Playing, GameCommand::ReportTricks(tricks)) => {
+                self.current_round_mut()?.report_tricks(tricks)
+            }
+            (WizardState::Playing, GameCommand::SetBid { player, bid }) => {
+                self.current_round_mut()?.set_bid(player, bid)
+            }
+            (WizardState::Playing, GameCommand::SetTricks { player, tricks }) => {
+                self.current_round_mut()?.set_tricks(player, tricks)
+            }
+            (WizardState::Playing, GameCommand::SubmitBids(bids)) => {
+                self.current_round_mut()?.submit_bids(bids)
+            }
+            (WizardState::Playing, GameCommand::SubmitTricks(tricks)) => {
+                self.current_round_mut()?.submit_tricks(tricks)
+            }
+            (WizardState::Playing, GameCommand::RecordTrickWinner(player)) => {
+                self.current_round_mut()?.record_trick_winner(player)
+            }
+            (WizardState::Playing, GameCommand::ChooseTrump(suit)) => {
+                self.current_round_mut()?.choose_trump(suit)
+            }
+            (WizardState::Playing, GameCommand::PlayCard(card)) => {
+                self.current_round_mut()?.play_card(card)
+            }
+            (WizardState::Playing, GameCommand::ScoreRound) => self.score_round(),
+            (
+                _,
+                GameCommand::AmendRound {
+                    round_nr,
+                    tips,
+                    matches,
+                },
+            ) => self.amend(round_nr, tips, matches),
+            (_, GameCommand::RenamePlayer { player, name }) => self.rename(player, name),
+            (_, GameCommand::SetTrickEntry(trick_entry)) => {
+                self.set_trick_entry(trick_entry);
+                Ok(vec![])
+            }
+            (state, command) => Err(GameError::InvalidState { command, state }),
+        }
+    }
+
+    pub fn pending_input(&self) -> PendingInput {
+        match (self.state, &self.current_round) {
+            (WizardState::Init, _) => PendingInput::PlayerName {
+                seat: self.player_index,
+            },
+            (WizardState::Playing, Some(round)) => round.pending_input(),
+            _ => PendingInput::Nothing,
+        }
+    }
+
+    /// Replaces the tips and matches of an already scored round and recalculates the
+    /// standings of every later round.
+    pub fn amend_round(
+        &mut self,
+        round_nr: u32,
+        tips: Tips,
+        matches: Tips,
+    ) -> Result<Vec<GameEvent>, GameError> {
+        self.apply(GameCommand::AmendRound {
+            round_nr,
+            tips,
+            matches,
+        })
+    }
+
+    /// Renames a player, keeping their recorded tips, matches and points.
+    pub fn rename_player(
+        &mut self,
+        player: PlayerId,
+        name: String,
+    ) -> Result<Vec<GameEvent>, GameError> {
+        self.apply(GameCommand::RenamePlayer { player, name })
+    }
+
+    pub fn player(&self, id: PlayerId) -> Option<&Player> {
+        self.players.iter().find(|player| player.id == id)
+    }
+
+    pub fn rules(&self) -> &RuleSet {
+        &self.rules
+    }
+
+    pub fn trick_entry(&self) -> TrickEntry {
+        self.trick_entry
+    }
+
+    /// Which rounds are played, see [`GameCommand::SetGameLength`].
+    pub fn length(&self) -> GameLength {
+        self.length
+    }
+
+    /// Cards dealt in every round of the game.
+    pub fn schedule(&self) -> &[u32] {
+        &self.schedule
+    }
+
+    /// Most cards a player can get, the whole deck is dealt in the last round of a full game
+    /// unless the rules cap it.
+    pub fn max_cards(&self) -> u32 {
+        self.rules.max_cards(self.player_count)
+    }
+
+    /// The seed every round is shuffled with, games with the same seed and players get the
+    /// same deals. `None` only for games saved before they were dealt.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Replaces the seed before the first round is dealt, see [`WizardBuilder::seed`].
+    fn set_seed(&mut self, seed: u64) -> Vec<GameEvent> {
+        self.seed = Some(seed);
+        vec![]
+    }
+
+    /// Switches how tricks are recorded, including the round being played unless its tricks
+    /// are already being entered, then the switch applies from the next round. Card play is
+    /// only started or stopped during bidding.
+    fn set_trick_entry(&mut self, trick_entry: TrickEntry) {
+        self.trick_entry = trick_entry;
+        if let Some(round) = self.current_round.as_mut() {
+            let cards = [round.trick_entry, trick_entry].contains(&TrickEntry::Cards);
+            if round.state == RoundState::Tipping || (!cards && round.matches.is_empty()) {
+                round.trick_entry = trick_entry;
+            }
+        }
+    }
+
+    /// Picks which rounds are played, only possible before the first round started.
+    fn set_game_length(&mut self, length: GameLength) -> Result<Vec<GameEvent>, GameError> {
+        let schedule = length.schedule(self.max_cards());
+        if schedule.is_empty() {
+            return Err(GameError::InvalidGameLength { length });
+        }
+
+        self.length = length;
+        self.round_count = schedule.len();
+        self.schedule = schedule;
+
+        Ok(vec![])
+    }
+
+    /// Plays only the first `rounds` rounds of the schedule.
+    fn limit_rounds(&mut self, rounds: usize) -> Result<(), GameError> {
+        if rounds == 0 || rounds > self.round_count {
+            return Err(GameError::InvalidRoundLimit {
+                limit: rounds,
+                max: self.round_count,
+            });
+        }
+
+        self.schedule.truncate(rounds);
+        self.round_count = rounds;
+
+        Ok(())
+    }
+
+    /// The scored round with the given number.
+    pub fn round(&self, round_nr: u32) -> Option<&Round> {
+        self.rounds.iter().find(|round| round.round_nr == round_nr)
+    }
+
+    /// The scored rounds in the order they were played.
+    pub fn history(&self) -> impl Iterator<Item = RoundRecord> + '_ {
+        self.rounds.iter().filter_map(RoundRecord::from_round)
+    }
+
+    /// Total points of the player in `seat` after the given round was scored.
+    pub fn points_after(&self, round_nr: u32, seat: usize) -> Option<i16> {
+        self.round(round_nr)?
+            .player_at(seat)
+            .map(|player| player.points)
+    }
+
+    /// The deal moves one seat to the left every round, starting with the first dealer.
+    fn dealer_seat(&self, round_nr: usize) -> usize {
+        (self.first_dealer_seat + round_nr - 1) % self.player_count
+    }
+
+    fn current_round_mut(&mut self) -> Result<&mut Round, GameError> {
+        self.current_round.as_mut().ok_or(GameError::NoCurrentRound)
+    }
+
+    fn add_player(&mut self, name: String) -> Vec<GameEvent> {
+        let id = PlayerId(self.player_index as u32);
+        self.players
+            .insert(self.player_index, Player::new(id, name.to_string()));
+
+        let events = vec![GameEvent::PlayerAdded { player: id, name }];
+
+        if self.player_index + 1 == self.player_count {
+            self.state = WizardState::NextRound;
+            self.player_index = 0;
+        } else {
+            self.player_index += 1;
+        }
+
+        events
+    }
+
+    fn start_next_round(&mut self) -> Vec<GameEvent> {
+        self.round_index += 1;
+        self.current_round = Option::from(Round::new(
+            self.round_index as u32,
+            self.schedule[self.round_index - 1],
+            self.players.to_vec(),
+            self.dealer_seat(self.round_index),
+            self.rules,
+        ));
+        // round n is shuffled with seed + n, so any round can be dealt again on its own
+        let seed = *self.seed.get_or_insert_with(cards::random_seed);
+        if let Some(round) = self.current_round.as_mut() {
+            round.deal(seed.wrapping_add(round.round_nr as u64));
+        }
+        self.set_trick_entry(self.trick_entry);
+
+        self.state = WizardState::Playing;
+
+        vec![GameEvent::RoundStarted {
+            round_nr: self.round_index as u32,
+        }]
+    }
+
+    fn score_round(&mut self) -> Result<Vec<GameEvent>, GameError> {
+        let current_round = self.current_round_mut()?;
+        let mut events = current_round.score()?;
+        let current_round = current_round.to_owned();
+
+        self.players = current_round.players.to_vec();
+        self.rounds.insert(self.round_index - 1, current_round);
+
+        if self.round_index == self.round_count {
+            self.state = WizardState::End;
+            events.push(GameEvent::GameEnded);
+        } else {
+            self.state = WizardState::EndRound;
+        }
+
+        Ok(events)
+    }
+
+    fn amend(
+        &mut self,
+        round_nr: u32,
+        tips: Tips,
+        matches: Tips,
+    ) -> Result<Vec<GameEvent>, GameError> {
+        let round = self
+            .rounds
+            .iter_mut()
+            .find(|round| round.round_nr == round_nr)
+            .ok_or(GameError::UnknownRound { round_nr })?;
+        round.validate_results(&tips, &matches)?;
+
+        round.tips = tips;
+        round.matches = matches;
+        self.recalculate();
+
+        Ok(vec![GameEvent::RoundAmended { round_nr }])
+    }
+
+    fn rename(&mut self, id: PlayerId, name: String) -> Result<Vec<GameEvent>, GameError> {
+        if self.player(id).is_none() {
+            return Err(GameError::UnknownPlayer { player: id });
+        }
+
+        let rounds = self.rounds.iter_mut().chain(self.current_round.as_mut());
+        let round_players = rounds.flat_map(|round| round.players.iter_mut());
+        for player in self.players.iter_mut().chain(round_players) {
+            if player.id == id {
+                player.name = name.to_string();
+            }
+        }
+
+        Ok(vec![GameEvent::PlayerRenamed { player: id, name }])
+    }
+
+    /// Rebuilds the running totals of all scored rounds from their tips and matches.
+    fn recalculate(&mut self) {
+        let mut totals: HashMap<PlayerId, i16> = HashMap::new();
+
+        for round in self.rounds.iter_mut() {
+            let deltas = round.points_deltas();
+            for (player, delta) in round.players.iter_mut().zip(deltas) {
+                let total = totals.entry(player.id).or_insert(0);
+                *total += delta;
+                player.points = *total;
+            }
+        }
+
+        if let Some(round) = self.current_round.as_mut() {
+            if round.state == RoundState::End {
+                if let Some(scored) = self.rounds.last() {
+                    *round = scored.to_owned();
+                }
+            } else {
+                for player in round.players.iter_mut() {
+                    player.points = totals.get(&player.id).copied().unwrap_or(0);
+                }
+            }
+        }
+
+        for player in self.players.iter_mut() {
+            player.points = totals.get(&player.id).copied().unwrap_or(0);
+        }
+    }
+}
+
+impl Display for Wizard {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for player in &self.players {
+            write!(f, "{} ", player)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assertor::*;
+
+    fn add_players(wizard: &mut Wizard) {
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            wizard
+                .apply(GameCommand::AddPlayer(String::from(name)))
+                .unwrap();
+        }
+    }
+
+    fn place_bids(wizard: &mut Wizard, bids: &[u8]) {
+        for bid in bids {
+            wizard.apply(GameCommand::PlaceBid(*bid)).unwrap();
+        }
+    }
+
+    fn report_tricks(wizard: &mut Wizard, tricks: &[u8]) {
+        for tricks in tricks {
+            wizard.apply(GameCommand::ReportTricks(*tricks)).unwrap();
+        }
+    }
+
+    #[test]
+    fn initialization_no_state_change_state_is_init() {
+        // arrange
+        // act
+        let wizard = Wizard::new(3, RuleSet::official());
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::Init);
+    }
+
+    #[test]
+    fn initialization_input_names_state_is_next_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+
+        // act
+        add_players(&mut wizard);
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::NextRound);
+        assert_that!(wizard.players[0].name).is_equal_to(String::from("Player 1"));
+        assert_that!(wizard.players[1].name).is_equal_to(String::from("Player 2"));
+        assert_that!(wizard.players[2].name).is_equal_to(String::from("Player 3"));
+    }
+
+    #[test]
+    fn initialization_input_names_state_is_playing() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+
+        // act
+        let events = wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // assert
+        assert_that!(events).contains_exactly(vec![GameEvent::RoundStarted { round_nr: 1 }]);
+        assert_that!(wizard.current_round).is_some();
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Tipping);
+
+        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+    }
+
+    #[test]
+    fn tip_first_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        place_bids(&mut wizard, &[1, 0, 1]);
+
+        // assert
+        assert_that!(wizard.current_round).is_some();
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Playing);
+
+        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+    }
+
+    #[test]
+    fn tip_first_round_dealer_bid_making_sum_equal_round_nr_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0]);
+
+        // act
+        let result = wizard.apply(GameCommand::PlaceBid(0));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::ForbiddenBid {
+            player: String::from("Player 3"),
+            bid: 0,
+        }));
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Tipping);
+        assert_that!(round.forbidden_bid()).is_equal_to(Some(0));
+    }
+
+    #[test]
+    fn forbidden_bid_is_unknown_until_everybody_else_has_bid() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1]);
+
+        // act
+        let round = wizard.current_round.unwrap();
+
+        // assert
+        assert_that!(round.forbidden_bid()).is_none();
+        assert_that!(round.dealer().map(|dealer| dealer.name())).is_equal_to(Some("Player 3"));
+    }
+
+    #[test]
+    fn tip_first_round_without_restriction_sum_may_equal_round_nr() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::unrestricted());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0]);
+
+        // act
+        let events = wizard.apply(GameCommand::PlaceBid(0)).unwrap();
+
+        // assert
+        assert_that!(events).contains(GameEvent::BiddingComplete);
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Playing);
+    }
+
+    #[test]
+    fn play_first_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+
+        // act
+        report_tricks(&mut wizard, &[1, 0, 0]);
+
+        // assert
+        assert_that!(wizard.current_round).is_some();
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Checking);
+
+        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+    }
+
+    #[test]
+    fn check_first_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+
+        // act
+        let events = wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // assert
+        assert_that!(events).contains_exactly(vec![GameEvent::RoundScored { round_nr: 1 }]);
+        assert_that!(wizard.current_round).is_some();
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::End);
+
+        assert_that!(wizard.state).is_equal_to(WizardState::EndRound);
+
+        assert_that!(wizard.players[0].points).is_equal_to(30);
+        assert_that!(wizard.players[1].points).is_equal_to(20);
+        assert_that!(wizard.players[2].points).is_equal_to(-10);
+    }
+
+    #[test]
+    fn deal_moves_to_next_seat_every_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // act
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // assert
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.dealer_seat()).is_equal_to(0);
+        assert_that!(round.first_bidder_seat()).is_equal_to(1);
+        let order: Vec<_> = round.bidding_order().map(Player::name).collect();
+        assert_that!(order).contains_exactly_in_order(vec!["Player 2", "Player 3", "Player 1"]);
+        assert_that!(wizard.players[0].name()).is_equal_to("Player 1");
+    }
+
+    #[test]
+    fn history_has_a_record_per_scored_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[0, 0, 0]);
+        report_tricks(&mut wizard, &[1, 1, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // act
+        let history: Vec<RoundRecord> = wizard.history().collect();
+
+        // assert
+        assert_that!(history.len()).is_equal_to(2);
+        let second = &history[1];
+        assert_that!(second.round_nr).is_equal_to(2);
+        assert_that!(second.dealer).is_equal_to(wizard.players[0].id());
+        let made: Vec<bool> = second.players.iter().map(|record| record.made).collect();
+        assert_that!(made).contains_exactly_in_order(vec![true, false, false]);
+        let totals: Vec<i16> = second.players.iter().map(|record| record.total).collect();
+        assert_that!(totals).contains_exactly_in_order(vec![50, 10, -20]);
+    }
+
+    #[test]
+    fn tips_iterate_over_recorded_values() {
+        // arrange
+        let players = [
+            Player::new(PlayerId(0), String::from("Player 1")),
+            Player::new(PlayerId(1), String::from("Player 2")),
+        ];
+        let mut tips = Tips::new();
+        tips.add_tip(&players[0], 2);
+        tips.add_tip(&players[1], 0);
+
+        // act
+        let mut values: Vec<(PlayerId, u8)> = tips.iter().collect();
+        values.sort();
+
+        // assert
+        assert_that!(values).contains_exactly_in_order(vec![(PlayerId(0), 2), (PlayerId(1), 0)]);
+        assert_that!(tips.len()).is_equal_to(2);
+    }
+
+    #[test]
+    fn points_after_is_keyed_by_seat() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[0, 0, 0]);
+        report_tricks(&mut wizard, &[1, 1, 0]);
+
+        // act
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // assert
+        // the second round was bid by Player 2, Player 3 and Player 1 in that order
+        assert_that!(wizard.points_after(2, 0)).is_equal_to(Some(50));
+        assert_that!(wizard.points_after(2, 1)).is_equal_to(Some(10));
+        assert_that!(wizard.points_after(2, 2)).is_equal_to(Some(-20));
+        assert_that!(wizard.points_after(3, 0)).is_none();
+    }
+
+    #[test]
+    fn pending_input_during_init_asks_for_next_seat() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        wizard
+            .apply(GameCommand::AddPlayer(String::from("Player 1")))
+            .unwrap();
+
+        // act
+        let pending = wizard.pending_input();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::PlayerName { seat: 1 });
+    }
+
+    #[test]
+    fn pending_input_last_bidder_has_forbidden_bid() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[0, 0]);
+
+        // act
+        let pending = wizard.pending_input();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::Bid {
+            player: PlayerId(2),
+            range: 0..=1,
+            forbidden: Some(1),
+        });
+    }
+
+    #[test]
+    fn pending_input_after_bidding_asks_for_tricks() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+
+        // act
+        let pending = wizard.pending_input();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::Tricks {
+            player: PlayerId(0),
+            range: 0..=1,
+        });
+    }
+
+    #[test]
+    fn pending_input_while_checking_is_nothing() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+
+        // act
+        let pending = wizard.pending_input();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::Nothing);
+    }
+
+    #[test]
+    fn undo_bid_asks_for_same_player_again() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0]);
+
+        // act
+        wizard.undo().unwrap();
+
+        // assert
+        assert_that!(wizard.pending_input()).is_equal_to(PendingInput::Bid {
+            player: PlayerId(1),
+            range: 0..=1,
+            forbidden: None,
+        });
+        assert_that!(wizard.can_redo()).is_true();
+    }
+
+    #[test]
+    fn undo_score_round_reverts_rotation() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // act
+        wizard.undo().unwrap();
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::Playing);
+        assert_that!(wizard.rounds).is_empty();
+        assert_that!(wizard.players[0].name).is_equal_to(String::from("Player 1"));
+        assert_that!(wizard.players[0].points).is_equal_to(0);
+    }
+
+    #[test]
+    fn redo_reapplies_undone_command() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        let expected = wizard.current_round.clone();
+        wizard.undo().unwrap();
+
+        // act
+        wizard.redo().unwrap();
+
+        // assert
+        assert_that!(wizard.current_round).is_equal_to(expected);
+        assert_that!(wizard.can_redo()).is_false();
+    }
+
+    #[test]
+    fn new_command_after_undo_clears_redo() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        wizard
+            .apply(GameCommand::AddPlayer(String::from("Player 1")))
+            .unwrap();
+        wizard.undo().unwrap();
+
+        // act
+        wizard
+            .apply(GameCommand::AddPlayer(String::from("Player 2")))
+            .unwrap();
+
+        // assert
+        assert_that!(wizard.redo()).is_equal_to(Err(GameError::NothingToRedo));
+        assert_that!(wizard.players[0].name).is_equal_to(String::from("Player 2"));
+    }
+
+    #[test]
+    fn undo_without_history_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+
+        // act
+        let result = wizard.undo();
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::NothingToUndo));
+    }
+
+    #[test]
+    fn check_first_round_with_zero_bid_bonus() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::zero_bid_bonus());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+
+        // act
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        // assert
+        let points: Vec<i16> = wizard.rounds[0].players.iter().map(|p| p.points).collect();
+        assert_that!(points).contains_exactly_in_order(vec![30, 30, -10]);
+    }
+
+    #[test]
+    fn amend_round_recalculates_later_rounds() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[0, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 1]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        let players = wizard.rounds[0].players.to_vec();
+        let mut tips = Tips::new();
+        let mut matches = Tips::new();
+        for (player, (tip, matched)) in players.iter().zip([(1, 0), (0, 0), (1, 1)]) {
+            tips.add_tip(player, tip);
+            matches.add_tip(player, matched);
+        }
+
+        // act
+        let events = wizard.amend_round(1, tips, matches).unwrap();
+
+        // assert
+        assert_that!(events).contains_exactly(vec![GameEvent::RoundAmended { round_nr: 1 }]);
+        let first_round: Vec<i16> = wizard.rounds[0].players.iter().map(|p| p.points).collect();
+        assert_that!(first_round).contains_exactly_in_order(vec![-10, 20, 30]);
+
+        // round two was bid by Player 2, Player 3 and Player 1
+        let second_round: Vec<i16> = wizard.rounds[1].players.iter().map(|p| p.points).collect();
+        assert_that!(second_round).contains_exactly_in_order(vec![20, 10, 50]);
+        assert_that!(wizard.players.iter().map(|p| p.points).sum::<i16>()).is_equal_to(80);
+    }
+
+    #[test]
+    fn amend_round_with_mismatching_tricks_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+
+        let mut tips = Tips::new();
+        let mut matches = Tips::new();
+        for player in wizard.rounds[0].players.iter() {
+            tips.add_tip(player, 0);
+            matches.add_tip(player, 0);
+        }
+
+        // act
+        let result = wizard.amend_round(1, tips, matches);
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::TricksMismatch {
+            total: 0,
+            round_nr: 1,
+            tricks: 1,
+        }));
+        assert_that!(wizard.rounds[0].players[0].points).is_equal_to(30);
+    }
+
+    #[test]
+    fn players_with_same_name_keep_separate_tips() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        for name in ["Alex", "Alex", "Sam"] {
+            wizard
+                .apply(GameCommand::AddPlayer(String::from(name)))
+                .unwrap();
+        }
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        place_bids(&mut wizard, &[1, 1, 1]);
+
+        // assert
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.tips.sum()).is_equal_to(3);
+        assert_that!(round.players[0].id()).is_not_equal_to(round.players[1].id());
+    }
+
+    #[test]
+    fn rename_player_keeps_record() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1, 0, 0]);
+        wizard.apply(GameCommand::ScoreRound).unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        let events = wizard
+            .rename_player(PlayerId(0), String::from("Alex"))
+            .unwrap();
+
+        // assert
+        assert_that!(events).contains_exactly(vec![GameEvent::PlayerRenamed {
+            player: PlayerId(0),
+            name: String::from("Alex"),
+        }]);
+        let player = wizard.player(PlayerId(0)).unwrap();
+        assert_that!(player.name()).is_equal_to("Alex");
+        assert_that!(player.points).is_equal_to(30);
+        assert_that!(wizard.rounds[0].players[0].name()).is_equal_to("Alex");
+        assert_that!(wizard.rounds[0].tips.get(player)).is_equal_to(Some(1));
+    }
+
+    #[test]
+    fn rename_unknown_player_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+
+        // act
+        let result = wizard.rename_player(PlayerId(7), String::from("Alex"));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::UnknownPlayer {
+            player: PlayerId(7),
+        }));
+    }
+
+    #[test]
+    fn tricks_above_round_nr_are_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 1, 1]);
+
+        // act
+        let result = wizard.apply(GameCommand::ReportTricks(2));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::TricksOutOfRange { tricks: 2, max: 1 }));
+    }
+
+    #[test]
+    fn tricks_exceeding_round_are_rejected_before_last_player() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 1, 1]);
+        report_tricks(&mut wizard, &[1]);
+
+        // act
+        let result = wizard.apply(GameCommand::ReportTricks(1));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::TricksExceedRound {
+            total: 2,
+            round_nr: 1,
+            tricks: 1,
+        }));
+        assert_that!(result.unwrap_err().to_string()).is_equal_to(String::from(
+            "tricks total 2 exceeds the 1 tricks of round 1",
+        ));
+    }
+
+    #[test]
+    fn tricks_not_adding_up_are_explained() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 1, 1]);
+        report_tricks(&mut wizard, &[0, 0]);
+
+        // act
+        let round = wizard.current_round.as_ref().unwrap();
+        let result = round.validate_tricks(round.players[2].id(), 0);
+
+        // assert
+        assert_that!(result.unwrap_err().to_string())
+            .is_equal_to(String::from("tricks total 0 but round 1 has 1 tricks"));
+    }
+
+    #[test]
+    fn bids_can_be_set_in_any_order() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        wizard
+            .apply(GameCommand::SetBid {
+                player: PlayerId(1),
+                bid: 0,
+            })
+            .unwrap();
+
+        // assert
+        assert_that!(wizard.pending_input()).is_equal_to(PendingInput::Bid {
+            player: PlayerId(0),
+            range: 0..=1,
+            forbidden: None,
+        });
+        let events = wizard.apply(GameCommand::PlaceBid(0)).unwrap();
+        assert_that!(events).contains_exactly(vec![GameEvent::BidPlaced {
+            player: PlayerId(0),
+            bid: 0,
+        }]);
+    }
+
+    #[test]
+    fn dealer_has_to_bid_last_with_bid_sum_restriction() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        let result = wizard.apply(GameCommand::SetBid {
+            player: PlayerId(2),
+            bid: 1,
+        });
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::DealerBidsLast {
+            player: String::from("Player 3"),
+        }));
+    }
+
+    #[test]
+    fn dealer_may_bid_early_without_bid_sum_restriction() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::unrestricted());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        let result = wizard.apply(GameCommand::SetBid {
+            player: PlayerId(2),
+            bid: 1,
+        });
+
+        // assert
+        assert_that!(result).is_ok();
+    }
+
+    #[test]
+    fn submit_bids_places_dealer_bid_last() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        let bids = Tips::from_iter([(PlayerId(2), 1), (PlayerId(0), 1), (PlayerId(1), 0)]);
+
+        // act
+        let events = wizard.apply(GameCommand::SubmitBids(bids)).unwrap();
+
+        // assert
+        assert_that!(events).contains_exactly_in_order(vec![
+            GameEvent::BidPlaced {
+                player: PlayerId(0),
+                bid: 1,
+            },
+            GameEvent::BidPlaced {
+                player: PlayerId(1),
+                bid: 0,
+            },
+            GameEvent::BidPlaced {
+                player: PlayerId(2),
+                bid: 1,
+            },
+            GameEvent::BiddingComplete,
+        ]);
+    }
+
+    #[test]
+    fn submit_bids_with_forbidden_bid_places_nothing() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        let bids = Tips::from_iter([(PlayerId(0), 1), (PlayerId(1), 0), (PlayerId(2), 0)]);
+
+        // act
+        let result = wizard.apply(GameCommand::SubmitBids(bids));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::ForbiddenBid {
+            player: String::from("Player 3"),
+            bid: 0,
+        }));
+        assert_that!(wizard.current_round.unwrap().tips.is_empty()).is_true();
+    }
+
+    #[test]
+    fn tricks_can_be_corrected_before_everybody_reported() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[1]);
+
+        // act
+        wizard
+            .apply(GameCommand::SetTricks {
+                player: PlayerId(0),
+                tricks: 0,
+            })
+            .unwrap();
+        let events = wizard
+            .apply(GameCommand::SubmitTricks(Tips::from_iter([
+                (PlayerId(1), 0),
+                (PlayerId(2), 1),
+            ])))
+            .unwrap();
+
+        // assert
+        assert_that!(events).contains(GameEvent::TricksComplete);
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Checking);
+        assert_that!(round.matches.get(&round.players[0])).is_equal_to(Some(0));
+    }
+
+    #[test]
+    fn trick_winners_accumulate_matches() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard
+            .apply(GameCommand::SetTrickEntry(TrickEntry::Winners))
+            .unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+
+        // act
+        let pending = wizard.pending_input();
+        let events = wizard
+            .apply(GameCommand::RecordTrickWinner(PlayerId(2)))
+            .unwrap();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::TrickWinner { trick: 1, of: 1 });
+        assert_that!(events).contains_exactly_in_order(vec![
+            GameEvent::TrickWon {
+                player: PlayerId(2),
+                trick: 1,
+            },
+            GameEvent::TricksComplete,
+        ]);
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Checking);
+        assert_that!(round.matches.get(&round.players[0])).is_equal_to(Some(0));
+        assert_that!(round.matches.get(&round.players[2])).is_equal_to(Some(1));
+    }
+
+    /// A one card round played with cards, Player 1 holds Red 5, Player 2 Red 9 and Player 3
+    /// Blue 13, with `trump_card` turned up.
+    fn card_round(trump_card: Card) -> Wizard {
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard
+            .apply(GameCommand::SetTrickEntry(TrickEntry::Cards))
+            .unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        let round = wizard.current_round.as_mut().unwrap();
+        let hands = [(Suit::Red, 5), (Suit::Red, 9), (Suit::Blue, 13)];
+        round.hands = (0..)
+            .zip(hands)
+            .map(|(id, (suit, value))| (PlayerId(id), vec![Card::Number { suit, value }]))
+            .collect();
+        round.trump_card = Some(trump_card);
+        round.trump = trump_card.suit();
+
+        wizard
+    }
+
+    #[test]
+    fn played_tricks_feed_matches() {
+        // arrange
+        let mut wizard = card_round(Card::Jester);
+        place_bids(&mut wizard, &[1, 1, 1]);
+        let red = |value| Card::Number {
+            suit: Suit::Red,
+            value,
+        };
+        wizard.apply(GameCommand::PlayCard(red(5))).unwrap();
+        wizard.apply(GameCommand::PlayCard(red(9))).unwrap();
+
+        // act
+        let pending = wizard.pending_input();
+        let events = wizard
+            .apply(GameCommand::PlayCard(Card::Number {
+                suit: Suit::Blue,
+                value: 13,
+            }))
+            .unwrap();
+
+        // assert
+        assert_that!(pending).is_equal_to(PendingInput::Card {
+            player: PlayerId(2),
+        });
+        assert_that!(events).contains_exactly_in_order(vec![
+            GameEvent::CardPlayed {
+                player: PlayerId(2),
+                card: Card::Number {
+                    suit: Suit::Blue,
+                    value: 13,
+                },
+            },
+            GameEvent::TrickWon {
+                player: PlayerId(1),
+                trick: 1,
+            },
+            GameEvent::TricksComplete,
+        ]);
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.matches.get(&round.players[0])).is_equal_to(Some(0));
+        assert_that!(round.matches.get(&round.players[1])).is_equal_to(Some(1));
+    }
+
+    #[test]
+    fn card_not_in_hand_is_rejected() {
+        // arrange
+        let mut wizard = card_round(Card::Jester);
+        place_bids(&mut wizard, &[1, 1, 1]);
+
+        // act
+        let result = wizard.apply(GameCommand::PlayCard(Card::Wizard));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::CardNotInHand {
+            player: String::from("Player 1"),
+            card: Card::Wizard,
+        }));
+    }
+
+    #[test]
+    fn games_with_the_same_seed_get_the_same_deals() {
+        // arrange
+        let new_game = || {
+            let mut wizard = Wizard::new(3, RuleSet::official());
+            wizard.apply(GameCommand::SetSeed(1234)).unwrap();
+            add_players(&mut wizard);
+            wizard.apply(GameCommand::StartNextRound).unwrap();
+            wizard
+        };
+
+        // act
+        let (first, second) = (new_game(), new_game());
+
+        // assert
+        assert_that!(first.seed()).is_equal_to(Some(1234));
+        assert_that!(first.current_round).is_equal_to(second.current_round);
+    }
+
+    #[test]
+    fn reneging_is_rejected() {
+        // arrange
+        let mut wizard = card_round(Card::Jester);
+        let blue = Card::Number {
+            suit: Suit::Blue,
+            value: 2,
+        };
+        let red = Card::Number {
+            suit: Suit::Red,
+            value: 9,
+        };
+        let round = wizard.current_round.as_mut().unwrap();
+        round.hands.insert(PlayerId(1), vec![blue, red]);
+        place_bids(&mut wizard, &[1, 1, 1]);
+        wizard
+            .apply(GameCommand::PlayCard(Card::Number {
+                suit: Suit::Red,
+                value: 5,
+            }))
+            .unwrap();
+
+        // act
+        let result = wizard.apply(GameCommand::PlayCard(blue));
+
+        // assert
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.legal_moves(PlayerId(1))).contains_exactly_in_order(vec![red]);
+        assert_that!(result).is_equal_to(Err(GameError::MustFollowSuit {
+            player: String::from("Player 2"),
+            suit: Suit::Red,
+        }));
+    }
+
+    #[test]
+    fn turned_up_wizard_lets_dealer_choose_trump_before_bidding() {
+        // arrange
+        let mut wizard = card_round(Card::Wizard);
+        let rejected = wizard.apply(GameCommand::PlaceBid(0));
+
+        // act
+        let pending = wizard.pending_input();
+        let events = wizard.apply(GameCommand::ChooseTrump(Suit::Blue)).unwrap();
+
+        // assert
+        assert_that!(rejected).is_equal_to(Err(GameError::TrumpNotChosen {
+            player: String::from("Player 3"),
+        }));
+        assert_that!(pending).is_equal_to(PendingInput::Trump {
+            player: PlayerId(2),
+        });
+        assert_that!(events).contains_exactly_in_order(vec![GameEvent::TrumpChosen {
+            player: PlayerId(2),
+            suit: Suit::Blue,
+        }]);
+        assert_that!(wizard.current_round.as_ref().unwrap().trump()).is_equal_to(Some(Suit::Blue));
+    }
+
+    #[test]
+    fn tricks_of_another_trick_entry_are_rejected() {
+        // arrange
+        let round_with = |trick_entry| {
+            let mut wizard = Wizard::new(3, RuleSet::official());
+            add_players(&mut wizard);
+            wizard
+                .apply(GameCommand::SetTrickEntry(trick_entry))
+                .unwrap();
+            wizard.apply(GameCommand::StartNextRound).unwrap();
+            place_bids(&mut wizard, &[1, 0, 1]);
+            wizard
+        };
+        let mut winners = round_with(TrickEntry::Winners);
+        let mut totals = round_with(TrickEntry::Totals);
+
+        // act
+        let reported = winners.apply(GameCommand::ReportTricks(1));
+        let recorded = totals.apply(GameCommand::RecordTrickWinner(PlayerId(0)));
+
+        // assert
+        assert_that!(reported).is_equal_to(Err(GameError::WrongTrickEntry {
+            trick_entry: TrickEntry::Winners,
+        }));
+        assert_that!(recorded).is_equal_to(Err(GameError::WrongTrickEntry {
+            trick_entry: TrickEntry::Totals,
+        }));
+    }
+
+    #[test]
+    fn trick_entry_switched_after_tricks_were_entered_applies_to_next_round() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        wizard
+            .apply(GameCommand::SetTricks {
+                player: PlayerId(0),
+                tricks: 1,
+            })
+            .unwrap();
+
+        // act
+        wizard
+            .apply(GameCommand::SetTrickEntry(TrickEntry::Winners))
+            .unwrap();
+        let recorded = wizard.apply(GameCommand::RecordTrickWinner(PlayerId(1)));
+
+        // assert
+        assert_that!(recorded).is_equal_to(Err(GameError::WrongTrickEntry {
+            trick_entry: TrickEntry::Totals,
+        }));
+        assert_that!(wizard.trick_entry()).is_equal_to(TrickEntry::Winners);
+        let round = wizard.current_round.as_ref().unwrap();
+        assert_that!(round.trick_entry()).is_equal_to(TrickEntry::Totals);
+    }
+
+    #[test]
+    fn tricks_of_a_card_round_cannot_be_entered() {
+        // arrange
+        let mut wizard = card_round(Card::Jester);
+        place_bids(&mut wizard, &[1, 1, 1]);
+        let rejected = Err(GameError::WrongTrickEntry {
+            trick_entry: TrickEntry::Cards,
+        });
+
+        // act
+        let submitted = wizard.apply(GameCommand::SubmitTricks(Tips::from_iter([
+            (PlayerId(0), 1),
+            (PlayerId(1), 0),
+            (PlayerId(2), 0),
+        ])));
+        let reported = wizard.apply(GameCommand::ReportTricks(1));
+        let recorded = wizard.apply(GameCommand::RecordTrickWinner(PlayerId(0)));
+
+        // assert
+        assert_that!(submitted).is_equal_to(rejected.clone());
+        assert_that!(reported).is_equal_to(rejected.clone());
+        assert_that!(recorded).is_equal_to(rejected);
+    }
+
+    #[test]
+    fn trump_is_turned_up_unless_the_whole_deck_is_dealt() {
+        // arrange
+        let game = |builder: WizardBuilder| builder.players(["Ann", "Bob", "Cid"]).start().unwrap();
+
+        // act
+        let shortened = game(Wizard::builder().round_limit(1));
+        let whole_deck = game(Wizard::builder().length(GameLength::StartingAt(20)));
+
+        // assert
+        let shortened = shortened.current_round.as_ref().unwrap();
+        let whole_deck = whole_deck.current_round.as_ref().unwrap();
+        assert_that!(shortened.trump_card()).is_some();
+        assert_that!(whole_deck.trump_card()).is_none();
+        assert_that!(whole_deck.trump()).is_none();
+    }
+
+    #[test]
+    fn trick_winner_of_unknown_player_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard
+            .apply(GameCommand::SetTrickEntry(TrickEntry::Winners))
+            .unwrap();
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+
+        // act
+        let result = wizard.apply(GameCommand::RecordTrickWinner(PlayerId(9)));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::UnknownPlayer {
+            player: PlayerId(9),
+        }));
+    }
+
+    #[test]
+    fn up_and_down_game_deals_fewer_cards_after_the_top() {
+        // arrange
+        let mut wizard = Wizard::new(6, RuleSet::unrestricted());
+        for name in ["A", "B", "C", "D", "E", "F"] {
+            wizard
+                .apply(GameCommand::AddPlayer(String::from(name)))
+                .unwrap();
+        }
+        wizard
+            .apply(GameCommand::SetGameLength(GameLength::UpAndDown))
+            .unwrap();
+
+        // act
+        let schedule = wizard.schedule().to_vec();
+
+        // assert
+        assert_that!(wizard.round_count).is_equal_to(19);
+        assert_that!(schedule[9]).is_equal_to(10);
+        assert_that!(schedule[10]).is_equal_to(9);
+    }
+
+    #[test]
+    fn game_length_cannot_change_once_playing() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        let result = wizard.apply(GameCommand::SetGameLength(GameLength::OddRounds));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidState {
+            command: GameCommand::SetGameLength(GameLength::OddRounds),
+            state: WizardState::Playing,
+        }));
+    }
+
+    #[test]
+    fn command_in_wrong_state_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+
+        // act
+        let result = wizard.apply(GameCommand::PlaceBid(1));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidState {
+            command: GameCommand::PlaceBid(1),
+            state: WizardState::Init,
+        }));
+        assert_that!(wizard.state).is_equal_to(WizardState::Init);
+    }
+
+    #[test]
+    fn try_new_without_players_is_rejected() {
+        // arrange
+        // act
+        let result = Wizard::try_new(0, RuleSet::official());
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::InvalidPlayerCount {
+            count: 0,
+            min: 3,
+            max: 6,
+        }));
+    }
+
+    #[test]
+    fn large_table_deals_from_two_decks() {
+        // arrange
+        // act
+        let wizard = Wizard::new(8, RuleSet::large_table());
+
+        // assert
+        assert_that!(wizard.round_count).is_equal_to(15);
+        assert_that!(wizard.schedule().last()).is_equal_to(Some(&15));
+    }
+
+    #[test]
+    fn starting_a_round_deals_every_player_a_hand() {
+        // arrange
+        let mut wizard = Wizard::builder()
+            .players(["Ann", "Bob", "Cid"])
+            .seed(7)
+            .build()
+            .unwrap();
+
+        // act
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // assert
+        let round = wizard.current_round.as_ref().unwrap();
+        for player in &wizard.players {
+            assert_that!(round.hand(player.id()).map(<[Card]>::len)).is_equal_to(Some(1));
+        }
+    }
+
+    #[test]
+    fn bid_above_round_nr_is_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+
+        // act
+        let result = wizard.apply(GameCommand::PlaceBid(2));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::BidOutOfRange { bid: 2, max: 1 }));
+        assert_that!(wizard.pending_input()).is_equal_to(PendingInput::Bid {
+            player: PlayerId(0),
+            range: 0..=1,
+            forbidden: None,
+        });
+    }
+
+    #[test]
+    fn tricks_not_matching_round_nr_are_rejected() {
+        // arrange
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        add_players(&mut wizard);
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        place_bids(&mut wizard, &[1, 0, 1]);
+        report_tricks(&mut wizard, &[0, 0]);
+
+        // act
+        let result = wizard.apply(GameCommand::ReportTricks(0));
+
+        // assert
+        assert_that!(result).is_equal_to(Err(GameError::TricksMismatch {
+            total: 0,
+            round_nr: 1,
+            tricks: 1,
+        }));
+        let round = wizard.current_round.unwrap();
+        assert_that!(round.state).is_equal_to(RoundState::Playing);
     }
 }
@@ -0,0 +1,221 @@
+use crate::{ScoreBreakdown, ScorePart, ScoreRule, MAX_PLAYERS, MIN_PLAYERS};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// Number of cards in one Wizard deck.
+pub const DECK_SIZE: u32 = 60;
+
+/// Scoring constants and bidding restrictions a game is played with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RuleSet {
+    /// Points for hitting the bid exactly.
+    pub base_points: i16,
+    /// Additional points per trick when the bid was hit.
+    pub points_per_trick: i16,
+    /// Points lost per trick the player was off.
+    pub penalty_per_trick: i16,
+    /// Whether the sum of all bids may not equal the number of tricks in the round.
+    pub bid_sum_restriction: bool,
+    /// Extra points for hitting a bid of zero.
+    pub zero_bid_bonus: i16,
+    /// Number of decks shuffled together.
+    pub decks: u8,
+    /// Most cards a player gets in a round, even if the decks would allow more.
+    pub max_cards: Option<u8>,
+    /// Fewest players these rules are meant for.
+    pub min_players: u8,
+    /// Most players these rules are meant for.
+    pub max_players: u8,
+}
+
+impl RuleSet {
+    /// 20 points for a correct bid, 10 per trick, -10 per trick off and bids may not add up.
+    pub fn official() -> Self {
+        Self {
+            base_points: 20,
+            points_per_trick: 10,
+            penalty_per_trick: 10,
+            bid_sum_restriction: true,
+            zero_bid_bonus: 0,
+            decks: 1,
+            max_cards: None,
+            min_players: 3,
+            max_players: 6,
+        }
+    }
+
+    /// Official rules for two players, played to 20 cards instead of dealing the whole deck.
+    pub fn two_players() -> Self {
+        Self {
+            max_cards: Some(20),
+            min_players: 2,
+            max_players: 2,
+            ..Self::official()
+        }
+    }
+
+    /// Official rules for 7 to 12 players, dealt from two decks.
+    pub fn large_table() -> Self {
+        Self {
+            decks: 2,
+            min_players: 7,
+            max_players: 12,
+            ..Self::official()
+        }
+    }
+
+    /// The preset meant for `player_count` players, if there is one.
+    pub fn for_player_count(player_count: usize) -> Option<Self> {
+        [Self::two_players(), Self::official(), Self::large_table()]
+            .into_iter()
+            .find(|rules| rules.player_counts().contains(&player_count))
+    }
+
+    /// Player counts any of the presets supports.
+    pub fn supported_player_counts() -> RangeInclusive<usize> {
+        MIN_PLAYERS..=MAX_PLAYERS
+    }
+
+    /// Player counts these rules are meant for.
+    pub fn player_counts(&self) -> RangeInclusive<usize> {
+        self.min_players as usize..=self.max_players as usize
+    }
+
+    /// Most cards each of `player_count` players can get in a round.
+    pub fn max_cards(&self, player_count: usize) -> u32 {
+        let cards = DECK_SIZE * self.decks as u32 / player_count.max(1) as u32;
+        self.max_cards.map_or(cards, |max| cards.min(max as u32))
+    }
+
+    /// Official scoring, but the bids may add up to the number of tricks.
+    pub fn unrestricted() -> Self {
+        Self {
+            bid_sum_restriction: false,
+            ..Self::official()
+        }
+    }
+
+    /// Official rules with 10 extra points for a successful zero bid.
+    pub fn zero_bid_bonus() -> Self {
+        Self {
+            zero_bid_bonus: 10,
+            ..Self::official()
+        }
+    }
+
+    /// Points for a player who bid `bid` and took `tricks` tricks.
+    pub fn points(&self, bid: u8, tricks: u8) -> i16 {
+        self.breakdown(bid, tricks).total()
+    }
+
+    /// The parts that make up [`RuleSet::points`], skipping rules that do not apply.
+    pub fn breakdown(&self, bid: u8, tricks: u8) -> ScoreBreakdown {
+        let diff = u8::abs_diff(bid, tricks);
+        let part = |rule, count, points_each| ScorePart {
+            rule,
+            count,
+            points_each,
+        };
+
+        let parts = if diff != 0 {
+            vec![part(
+                ScoreRule::PenaltyPerTrick,
+                diff,
+                -self.penalty_per_trick,
+            )]
+        } else if bid == 0 && self.zero_bid_bonus != 0 {
+            vec![
+                part(ScoreRule::BasePoints, 1, self.base_points),
+                part(ScoreRule::ZeroBidBonus, 1, self.zero_bid_bonus),
+            ]
+        } else if bid == 0 {
+            vec![part(ScoreRule::BasePoints, 1, self.base_points)]
+        } else {
+            vec![
+                part(ScoreRule::BasePoints, 1, self.base_points),
+                part(ScoreRule::PointsPerTrick, bid, self.points_per_trick),
+            ]
+        };
+
+        ScoreBreakdown { bid, tricks, parts }
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::official()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assertor::*;
+
+    #[test]
+    fn official_points_for_correct_bid() {
+        // arrange
+        let rules = RuleSet::official();
+
+        // act
+        let points = rules.points(2, 2);
+
+        // assert
+        assert_that!(points).is_equal_to(40);
+    }
+
+    #[test]
+    fn official_points_for_missed_bid() {
+        // arrange
+        let rules = RuleSet::official();
+
+        // act
+        let points = rules.points(1, 3);
+
+        // assert
+        assert_that!(points).is_equal_to(-20);
+    }
+
+    #[test]
+    fn zero_bid_bonus_is_added_for_correct_zero_bid() {
+        // arrange
+        let rules = RuleSet::zero_bid_bonus();
+
+        // act
+        let points = rules.points(0, 0);
+
+        // assert
+        assert_that!(points).is_equal_to(30);
+    }
+
+    #[test]
+    fn presets_cover_supported_player_counts() {
+        // arrange
+        let player_counts = RuleSet::supported_player_counts();
+
+        // act
+        let presets: Vec<_> = player_counts
+            .clone()
+            .map(RuleSet::for_player_count)
+            .collect();
+
+        // assert
+        assert_that!(presets.iter().all(Option::is_some)).is_true();
+        assert_that!(RuleSet::for_player_count(player_counts.end() + 1)).is_none();
+    }
+
+    #[test]
+    fn max_cards_depends_on_decks_and_cap() {
+        // arrange
+        let two_players = RuleSet::two_players();
+        let large_table = RuleSet::large_table();
+
+        // act
+        let cards = (two_players.max_cards(2), large_table.max_cards(7));
+
+        // assert
+        assert_that!(cards).is_equal_to((20, 17));
+    }
+}
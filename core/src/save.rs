@@ -0,0 +1,604 @@
+//! JSON save format for [`Wizard`] games, available with the `serde` feature.
+//!
+//! A save file is a single JSON object with two fields:
+//!
+//! ```json
+//! {
+//!   "version": 12,
+//!   "game": { "state": "Playing", "round_count": 20, "round_index": 3, ... }
+//! }
+//! ```
+//!
+//! `version` is the schema version of `game`, which is the serialized [`Wizard`]
+//! (undo/redo history is not saved). Whenever the layout of `game` changes,
+//! [`SAVE_VERSION`] is bumped and a migration from the previous version is added,
+//! so [`from_json`] keeps loading files written by older versions of the crate.
+
+use crate::{RuleSet, TrickEntry, Wizard};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Schema version written by [`to_json`].
+pub const SAVE_VERSION: u32 = 12;
+
+/// Migrations from version `i + 1` to `i + 2`, applied in order on load.
+const MIGRATIONS: [fn(Value) -> Result<Value, SaveError>; 11] = [
+    migrate_v1_to_v2,
+    migrate_v2_to_v3,
+    migrate_v3_to_v4,
+    migrate_v4_to_v5,
+    migrate_v5_to_v6,
+    migrate_v6_to_v7,
+    migrate_v7_to_v8,
+    migrate_v8_to_v9,
+    migrate_v9_to_v10,
+    migrate_v10_to_v11,
+    migrate_v11_to_v12,
+];
+
+#[derive(Serialize)]
+struct SaveFileRef<'a> {
+    version: u32,
+    game: &'a Wizard,
+}
+
+#[derive(Deserialize)]
+struct SaveFile {
+    version: u32,
+    game: Value,
+}
+
+#[derive(Debug)]
+pub enum SaveError {
+    Json(serde_json::Error),
+    UnsupportedVersion { version: u32 },
+    Migration { version: u32, reason: String },
+}
+
+impl Display for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::Json(err) => write!(f, "invalid save file: {}", err),
+            SaveError::UnsupportedVersion { version } => write!(
+                f,
+                "save file version {} is not supported (current version is {})",
+                version, SAVE_VERSION
+            ),
+            SaveError::Migration { version, reason } => write!(
+                f,
+                "could not migrate save file from version {}: {}",
+                version, reason
+            ),
+        }
+    }
+}
+
+impl Error for SaveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            SaveError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for SaveError {
+    fn from(err: serde_json::Error) -> Self {
+        SaveError::Json(err)
+    }
+}
+
+pub fn to_json(game: &Wizard) -> Result<String, SaveError> {
+    Ok(serde_json::to_string_pretty(&to_value(game)?)?)
+}
+
+pub fn from_json(json: &str) -> Result<Wizard, SaveError> {
+    from_value(serde_json::from_str(json)?)
+}
+
+/// Like [`to_json`], but returns the save file as a JSON value for embedding it elsewhere.
+pub fn to_value(game: &Wizard) -> Result<Value, SaveError> {
+    let save_file = SaveFileRef {
+        version: SAVE_VERSION,
+        game,
+    };
+
+    Ok(serde_json::to_value(save_file)?)
+}
+
+pub fn from_value(value: Value) -> Result<Wizard, SaveError> {
+    let save_file: SaveFile = serde_json::from_value(value)?;
+    let game = migrate(save_file.version, save_file.game)?;
+
+    Ok(serde_json::from_value(game)?)
+}
+
+/// Brings the `game` value of a save file written with `version` up to [`SAVE_VERSION`].
+fn migrate(version: u32, mut game: Value) -> Result<Value, SaveError> {
+    if version == 0 || version > SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion { version });
+    }
+
+    for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+        game = migration(game)?;
+    }
+
+    Ok(game)
+}
+
+/// Version 2 added the [`RuleSet`] to the game and every round, older games used the official rules.
+fn migrate_v1_to_v2(mut game: Value) -> Result<Value, SaveError> {
+    let rules = serde_json::to_value(RuleSet::official())?;
+
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            round["rules"] = rules.clone();
+        }
+    }
+    if game["current_round"].is_object() {
+        game["current_round"]["rules"] = rules.clone();
+    }
+    game["rules"] = rules;
+
+    Ok(game)
+}
+
+/// Version 3 dropped the `Retipping` round state, such a round drops its bids and restarts
+/// bidding with the first player instead.
+fn migrate_v2_to_v3(mut game: Value) -> Result<Value, SaveError> {
+    let round = &mut game["current_round"];
+    if round["state"] == "Retipping" {
+        round["state"] = Value::from("Tipping");
+        round["current_player_index"] = Value::from(0);
+        round["tips"]["tips"] = Value::Object(Default::default());
+    }
+
+    Ok(game)
+}
+
+/// Version 4 identifies players by id instead of name. Ids are the seat numbers, which
+/// are recovered from the player order: every round starts one seat further along.
+fn migrate_v3_to_v4(mut game: Value) -> Result<Value, SaveError> {
+    let player_count = game["player_count"].as_u64().unwrap_or(0) as usize;
+    let rounds_played = game["rounds"].as_array().map_or(0, Vec::len);
+    let offset = if game["state"] == "Init" {
+        0
+    } else {
+        rounds_played
+    };
+
+    assign_player_ids(&mut game["players"], offset, player_count)?;
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            migrate_round_to_v4(round, player_count)?;
+        }
+    }
+    if game["current_round"].is_object() {
+        migrate_round_to_v4(&mut game["current_round"], player_count)?;
+    }
+
+    Ok(game)
+}
+
+fn migrate_round_to_v4(round: &mut Value, player_count: usize) -> Result<(), SaveError> {
+    let offset = round["round_nr"].as_u64().unwrap_or(1).saturating_sub(1) as usize;
+    let ids = assign_player_ids(&mut round["players"], offset, player_count)?;
+
+    for field in ["tips", "matches"] {
+        if let Some(tips) = round[field]["tips"].as_object_mut() {
+            *tips = tips
+                .iter()
+                .map(|(name, tip)| match ids.get(name) {
+                    Some(id) => Ok((id.to_string(), tip.clone())),
+                    None => Err(SaveError::Migration {
+                        version: 3,
+                        reason: format!("{} of unknown player {}", field, name),
+                    }),
+                })
+                .collect::<Result<_, _>>()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the seat id to every player and returns the ids by name.
+fn assign_player_ids(
+    players: &mut Value,
+    offset: usize,
+    player_count: usize,
+) -> Result<HashMap<String, usize>, SaveError> {
+    let mut ids = HashMap::new();
+
+    if let Some(players) = players.as_array_mut() {
+        for (index, player) in players.iter_mut().enumerate() {
+            let id = (index + offset) % player_count.max(1);
+            let name = player["name"].as_str().ok_or(SaveError::Migration {
+                version: 3,
+                reason: String::from("player without name"),
+            })?;
+            ids.insert(name.to_string(), id);
+            player["id"] = Value::from(id);
+        }
+    }
+
+    Ok(ids)
+}
+
+/// Version 5 keeps the players in seat order instead of rotating them every round and
+/// records the dealer of each round explicitly.
+fn migrate_v4_to_v5(mut game: Value) -> Result<Value, SaveError> {
+    let player_count = game["player_count"].as_u64().unwrap_or(0) as usize;
+
+    sort_players_by_seat(&mut game["players"]);
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            migrate_round_to_v5(round, player_count);
+        }
+    }
+    if game["current_round"].is_object() {
+        migrate_round_to_v5(&mut game["current_round"], player_count);
+    }
+
+    Ok(game)
+}
+
+fn migrate_round_to_v5(round: &mut Value, player_count: usize) {
+    let round_nr = round["round_nr"].as_u64().unwrap_or(1) as usize;
+    let player_count = player_count.max(1);
+    let dealer_seat = (round_nr + 2 * player_count - 2) % player_count;
+
+    sort_players_by_seat(&mut round["players"]);
+    round["dealer_seat"] = Value::from(dealer_seat);
+    round["first_bidder_seat"] = Value::from((dealer_seat + 1) % player_count);
+    if let Some(round) = round.as_object_mut() {
+        let turn = round
+            .remove("current_player_index")
+            .unwrap_or(Value::from(0));
+        round.insert(String::from("turn"), turn);
+    }
+}
+
+fn sort_players_by_seat(players: &mut Value) {
+    if let Some(players) = players.as_array_mut() {
+        players.sort_by_key(|player| player["id"].as_u64());
+    }
+}
+
+/// Version 6 derives whose turn it is from the missing bids and tricks, so the `turn`
+/// counter of each round is gone.
+fn migrate_v5_to_v6(mut game: Value) -> Result<Value, SaveError> {
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut().filter_map(Value::as_object_mut) {
+            round.remove("turn");
+        }
+    }
+    if let Some(round) = game["current_round"].as_object_mut() {
+        round.remove("turn");
+    }
+
+    Ok(game)
+}
+
+/// Version 7 added the [`TrickEntry`] mode, older games entered trick totals.
+fn migrate_v6_to_v7(mut game: Value) -> Result<Value, SaveError> {
+    let trick_entry = serde_json::to_value(TrickEntry::Totals)?;
+
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            round["trick_entry"] = trick_entry.clone();
+        }
+    }
+    if game["current_round"].is_object() {
+        game["current_round"]["trick_entry"] = trick_entry.clone();
+    }
+    game["trick_entry"] = trick_entry;
+
+    Ok(game)
+}
+
+/// Version 8 added the first dealer and the seed of a game, older games were dealt first by
+/// the last seat and had no seed.
+fn migrate_v7_to_v8(mut game: Value) -> Result<Value, SaveError> {
+    let player_count = game["player_count"].as_u64().unwrap_or(1);
+    game["first_dealer_seat"] = Value::from(player_count.saturating_sub(1));
+    game["seed"] = Value::Null;
+
+    Ok(game)
+}
+
+/// Version 9 added the game length and its schedule of cards per round, older games were
+/// played in full and dealt as many cards as the round number.
+fn migrate_v8_to_v9(mut game: Value) -> Result<Value, SaveError> {
+    let round_count = game["round_count"].as_u64().unwrap_or(0);
+    game["length"] = Value::from("Full");
+    game["schedule"] = (1..=round_count).collect();
+
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            round["cards"] = round["round_nr"].clone();
+        }
+    }
+    if game["current_round"].is_object() {
+        game["current_round"]["cards"] = game["current_round"]["round_nr"].clone();
+    }
+
+    Ok(game)
+}
+
+/// Version 10 added the decks and player counts to the [`RuleSet`], older games were played
+/// by 3 to 6 players with a single deck.
+fn migrate_v9_to_v10(mut game: Value) -> Result<Value, SaveError> {
+    fn migrate_rules(rules: &mut Value) {
+        rules["decks"] = Value::from(1);
+        rules["max_cards"] = Value::Null;
+        rules["min_players"] = Value::from(3);
+        rules["max_players"] = Value::from(6);
+    }
+
+    migrate_rules(&mut game["rules"]);
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            migrate_rules(&mut round["rules"]);
+        }
+    }
+    if game["current_round"].is_object() {
+        migrate_rules(&mut game["current_round"]["rules"]);
+    }
+
+    Ok(game)
+}
+
+/// Version 11 added the hands of every round, older rounds were never dealt.
+fn migrate_v10_to_v11(mut game: Value) -> Result<Value, SaveError> {
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        for round in rounds.iter_mut() {
+            round["hands"] = Value::Object(Default::default());
+        }
+    }
+    if game["current_round"].is_object() {
+        game["current_round"]["hands"] = Value::Object(Default::default());
+    }
+
+    Ok(game)
+}
+
+/// Version 12 added trump and the played tricks to every round, older rounds had neither.
+fn migrate_v11_to_v12(mut game: Value) -> Result<Value, SaveError> {
+    fn migrate_round(round: &mut Value) {
+        round["trump_card"] = Value::Null;
+        round["trump"] = Value::Null;
+        round["tricks"] = Value::Array(vec![]);
+    }
+
+    if let Some(rounds) = game["rounds"].as_array_mut() {
+        rounds.iter_mut().for_each(migrate_round);
+    }
+    if game["current_round"].is_object() {
+        migrate_round(&mut game["current_round"]);
+    }
+
+    Ok(game)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GameCommand, Player, PlayerId};
+    use assertor::*;
+
+    fn game_in_progress() -> Wizard {
+        let mut wizard = Wizard::new(3, RuleSet::official());
+        for name in ["Player 1", "Player 2", "Player 3"] {
+            wizard
+                .apply(GameCommand::AddPlayer(String::from(name)))
+                .unwrap();
+        }
+        wizard.apply(GameCommand::StartNextRound).unwrap();
+        wizard.apply(GameCommand::PlaceBid(1)).unwrap();
+
+        wizard
+    }
+
+    #[test]
+    fn save_and_load_restores_game() {
+        // arrange
+        let wizard = game_in_progress();
+
+        // act
+        let json = to_json(&wizard).unwrap();
+        let loaded = from_json(&json).unwrap();
+
+        // assert
+        assert_that!(loaded.state).is_equal_to(wizard.state);
+        assert_that!(loaded.players).is_equal_to(wizard.players.to_vec());
+        assert_that!(loaded.current_round).is_equal_to(wizard.current_round.clone());
+        assert_that!(loaded.pending_input()).is_equal_to(wizard.pending_input());
+    }
+
+    #[test]
+    fn save_contains_version() {
+        // arrange
+        let wizard = game_in_progress();
+
+        // act
+        let json = to_json(&wizard).unwrap();
+
+        // assert
+        let value: Value = serde_json::from_str(&json).unwrap();
+        assert_that!(value["version"]).is_equal_to(Value::from(SAVE_VERSION));
+    }
+
+    #[test]
+    fn load_version_1_uses_official_rules() {
+        // arrange
+        let json = r#"{
+            "version": 1,
+            "game": {
+                "state": "Init",
+                "round_count": 20,
+                "round_index": 0,
+                "player_count": 3,
+                "player_index": 0,
+                "players": [],
+                "rounds": [],
+                "current_round": null
+            }
+        }"#;
+
+        // act
+        let loaded = from_json(json).unwrap();
+
+        // assert
+        assert_that!(loaded.rules()).is_equal_to(&RuleSet::official());
+    }
+
+    #[test]
+    fn load_version_3_puts_players_in_seat_order() {
+        // arrange
+        let json = r#"{
+            "version": 3,
+            "game": {
+                "state": "Playing",
+                "round_count": 20,
+                "round_index": 2,
+                "player_count": 3,
+                "player_index": 3,
+                "players": [
+                    { "name": "B", "points": 20 },
+                    { "name": "C", "points": -10 },
+                    { "name": "A", "points": 30 }
+                ],
+                "rounds": [{
+                    "round_nr": 1,
+                    "state": "End",
+                    "tips": { "tips": { "A": 1, "B": 0, "C": 0 } },
+                    "matches": { "tips": { "A": 1, "B": 0, "C": 1 } },
+                    "players": [
+                        { "name": "A", "points": 30 },
+                        { "name": "B", "points": 20 },
+                        { "name": "C", "points": -10 }
+                    ],
+                    "current_player_index": 0,
+                    "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                               "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+                }],
+                "current_round": {
+                    "round_nr": 2,
+                    "state": "Tipping",
+                    "tips": { "tips": { "B": 2 } },
+                    "matches": { "tips": {} },
+                    "players": [
+                        { "name": "B", "points": 20 },
+                        { "name": "C", "points": -10 },
+                        { "name": "A", "points": 30 }
+                    ],
+                    "current_player_index": 1,
+                    "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                               "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+                },
+                "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                           "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+            }
+        }"#;
+
+        // act
+        let loaded = from_json(json).unwrap();
+
+        // assert
+        let first = &loaded.rounds[0];
+        let ids: Vec<_> = first.players.iter().map(Player::id).collect();
+        assert_that!(ids).contains_exactly_in_order(vec![PlayerId(0), PlayerId(1), PlayerId(2)]);
+        assert_that!(loaded.players[0].name()).is_equal_to("A");
+        assert_that!(first.matches.get(&first.players[2])).is_equal_to(Some(1));
+        let round = loaded.current_round.unwrap();
+        assert_that!(round.tips.get(&round.players[1])).is_equal_to(Some(2));
+        assert_that!(round.dealer_seat()).is_equal_to(0);
+        assert_that!(round.pending_input()).is_equal_to(crate::PendingInput::Bid {
+            player: PlayerId(2),
+            range: 0..=2,
+            forbidden: None,
+        });
+    }
+
+    #[test]
+    fn load_version_2_restarts_bidding_of_a_retipping_round() {
+        // arrange
+        let json = r#"{
+            "version": 2,
+            "game": {
+                "state": "Playing",
+                "round_count": 20,
+                "round_index": 2,
+                "player_count": 3,
+                "player_index": 3,
+                "players": [
+                    { "name": "B", "points": 20 },
+                    { "name": "C", "points": -10 },
+                    { "name": "A", "points": 30 }
+                ],
+                "rounds": [{
+                    "round_nr": 1,
+                    "state": "End",
+                    "tips": { "tips": { "A": 1, "B": 0, "C": 0 } },
+                    "matches": { "tips": { "A": 1, "B": 0, "C": 1 } },
+                    "players": [
+                        { "name": "A", "points": 30 },
+                        { "name": "B", "points": 20 },
+                        { "name": "C", "points": -10 }
+                    ],
+                    "current_player_index": 0,
+                    "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                               "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+                }],
+                "current_round": {
+                    "round_nr": 2,
+                    "state": "Retipping",
+                    "tips": { "tips": { "B": 1, "C": 0, "A": 1 } },
+                    "matches": { "tips": {} },
+                    "players": [
+                        { "name": "B", "points": 20 },
+                        { "name": "C", "points": -10 },
+                        { "name": "A", "points": 30 }
+                    ],
+                    "current_player_index": 2,
+                    "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                               "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+                },
+                "rules": { "base_points": 20, "points_per_trick": 10, "penalty_per_trick": 10,
+                           "bid_sum_restriction": true, "zero_bid_bonus": 0 }
+            }
+        }"#;
+
+        // act
+        let loaded = from_json(json).unwrap();
+
+        // assert
+        let round = loaded.current_round.unwrap();
+        assert_that!(round.tips.get(&round.players[0])).is_none();
+        assert_that!(round.pending_input()).is_equal_to(crate::PendingInput::Bid {
+            player: PlayerId(1),
+            range: 0..=2,
+            forbidden: None,
+        });
+    }
+
+    #[test]
+    fn load_newer_version_is_rejected() {
+        // arrange
+        let json = format!(r#"{{ "version": {}, "game": {{}} }}"#, SAVE_VERSION + 1);
+
+        // act
+        let result = from_json(&json);
+
+        // assert
+        assert_that!(matches!(
+            result,
+            Err(SaveError::UnsupportedVersion { version }) if version == SAVE_VERSION + 1
+        ))
+        .is_true();
+    }
+}
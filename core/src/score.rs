@@ -0,0 +1,110 @@
+use std::fmt::{Display, Formatter};
+
+/// The rule of a [`crate::RuleSet`] a part of a score comes from.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ScoreRule {
+    BasePoints,
+    PointsPerTrick,
+    PenaltyPerTrick,
+    ZeroBidBonus,
+}
+
+/// Points from a single rule, `count` times `points_each`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ScorePart {
+    pub rule: ScoreRule,
+    pub count: u8,
+    pub points_each: i16,
+}
+
+/// How the points of a player in a round add up, see [`crate::RuleSet::breakdown`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScoreBreakdown {
+    pub bid: u8,
+    pub tricks: u8,
+    pub parts: Vec<ScorePart>,
+}
+
+impl ScorePart {
+    pub fn points(&self) -> i16 {
+        i16::from(self.count) * self.points_each
+    }
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> i16 {
+        self.parts.iter().map(ScorePart::points).sum()
+    }
+
+    pub fn made(&self) -> bool {
+        self.bid == self.tricks
+    }
+}
+
+impl Display for ScorePart {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.rule {
+            ScoreRule::BasePoints | ScoreRule::ZeroBidBonus => write!(f, "{}", self.points_each),
+            ScoreRule::PointsPerTrick => write!(f, "{}×{}", self.count, self.points_each),
+            ScoreRule::PenaltyPerTrick => write!(f, "-{}×{}", self.count, -self.points_each),
+        }
+    }
+}
+
+/// Renders the breakdown compactly, e.g. `20 + 2×10 = 40` or `-2×10 = -20`.
+impl Display for ScoreBreakdown {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, part) in self.parts.iter().enumerate() {
+            if index > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{}", part)?;
+        }
+
+        write!(f, " = {}", self.total())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::RuleSet;
+    use assertor::*;
+
+    #[test]
+    fn breakdown_of_correct_bid() {
+        // arrange
+        let rules = RuleSet::official();
+
+        // act
+        let breakdown = rules.breakdown(2, 2);
+
+        // assert
+        assert_that!(breakdown.total()).is_equal_to(40);
+        assert_that!(breakdown.to_string()).is_equal_to(String::from("20 + 2×10 = 40"));
+    }
+
+    #[test]
+    fn breakdown_of_missed_bid() {
+        // arrange
+        let rules = RuleSet::official();
+
+        // act
+        let breakdown = rules.breakdown(1, 3);
+
+        // assert
+        assert_that!(breakdown.made()).is_false();
+        assert_that!(breakdown.to_string()).is_equal_to(String::from("-2×10 = -20"));
+    }
+
+    #[test]
+    fn breakdown_of_zero_bid_with_bonus() {
+        // arrange
+        let rules = RuleSet::zero_bid_bonus();
+
+        // act
+        let breakdown = rules.breakdown(0, 0);
+
+        // assert
+        assert_that!(breakdown.to_string()).is_equal_to(String::from("20 + 10 = 30"));
+    }
+}
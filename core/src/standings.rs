@@ -0,0 +1,166 @@
+use crate::{PlayerId, Wizard};
+use std::cmp::Ordering;
+
+/// Decides the order of players with the same points, see [`Wizard::standings_with`].
+/// Players still tied after all tie-breakers share their place.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TieBreaker {
+    /// More rounds in which the bid was hit exactly.
+    MostBidsMade,
+    /// More points gained in the last scored round.
+    BestLastRound,
+}
+
+/// A player's place in the game, places are shared on ties, e.g. 1, 1, 3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub place: usize,
+    pub player: PlayerId,
+    pub seat: usize,
+    pub points: i16,
+    pub bids_made: usize,
+    /// Points gained or lost in the last scored round.
+    pub last_round: i16,
+}
+
+impl TieBreaker {
+    fn compare(&self, a: &Standing, b: &Standing) -> Ordering {
+        match self {
+            TieBreaker::MostBidsMade => b.bids_made.cmp(&a.bids_made),
+            TieBreaker::BestLastRound => b.last_round.cmp(&a.last_round),
+        }
+    }
+}
+
+impl Wizard {
+    /// The players ranked by points, ties share their place.
+    pub fn standings(&self) -> Vec<Standing> {
+        self.standings_with(&[])
+    }
+
+    /// The players ranked by points, ties are broken by `tie_breakers` in order.
+    pub fn standings_with(&self, tie_breakers: &[TieBreaker]) -> Vec<Standing> {
+        let history: Vec<_> = self.history().collect();
+        let mut standings: Vec<Standing> = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| {
+                let records = history.iter().filter_map(|round| round.player(player.id()));
+                Standing {
+                    place: 0,
+                    player: player.id(),
+                    seat,
+                    points: player.points,
+                    bids_made: records.filter(|record| record.made).count(),
+                    last_round: history
+                        .last()
+                        .and_then(|round| round.player(player.id()))
+                        .map_or(0, |record| record.delta),
+                }
+            })
+            .collect();
+
+        let compare = |a: &Standing, b: &Standing| {
+            tie_breakers
+                .iter()
+                .fold(b.points.cmp(&a.points), |ordering, tie_breaker| {
+                    ordering.then_with(|| tie_breaker.compare(a, b))
+                })
+        };
+        standings.sort_by(|a, b| compare(a, b).then(a.seat.cmp(&b.seat)));
+
+        for index in 0..standings.len() {
+            standings[index].place = match index {
+                0 => 1,
+                _ if compare(&standings[index - 1], &standings[index]) == Ordering::Equal => {
+                    standings[index - 1].place
+                }
+                _ => index + 1,
+            };
+        }
+
+        standings
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{GameCommand, WizardBuilder, WizardState};
+    use assertor::*;
+
+    /// Ann and Cid both end with 60 points, Ann made all three bids and Cid scored more in the
+    /// last round.
+    fn tied_game() -> Wizard {
+        let mut wizard = WizardBuilder::new()
+            .players(["Ann", "Bob", "Cid"])
+            .round_limit(3)
+            .start()
+            .unwrap();
+        let rounds: [([u8; 3], [u8; 3]); 3] = [
+            ([0, 0, 0], [0, 0, 1]),
+            ([0, 1, 0], [1, 1, 0]),
+            ([2, 0, 0], [2, 0, 1]),
+        ];
+        for (index, (bids, tricks)) in rounds.into_iter().enumerate() {
+            if index > 0 {
+                wizard.apply(GameCommand::StartNextRound).unwrap();
+            }
+            for bid in bids {
+                wizard.apply(GameCommand::PlaceBid(bid)).unwrap();
+            }
+            for tricks in tricks {
+                wizard.apply(GameCommand::ReportTricks(tricks)).unwrap();
+            }
+            wizard.apply(GameCommand::ScoreRound).unwrap();
+        }
+
+        wizard
+    }
+
+    fn places(standings: &[Standing]) -> Vec<(usize, usize)> {
+        standings
+            .iter()
+            .map(|standing| (standing.place, standing.seat))
+            .collect()
+    }
+
+    #[test]
+    fn tied_players_share_place() {
+        // arrange
+        let wizard = tied_game();
+
+        // act
+        let standings = wizard.standings();
+
+        // assert
+        assert_that!(wizard.state).is_equal_to(WizardState::End);
+        assert_that!(places(&standings)).contains_exactly_in_order(vec![(1, 0), (1, 2), (3, 1)]);
+    }
+
+    #[test]
+    fn most_bids_made_breaks_tie() {
+        // arrange
+        let wizard = tied_game();
+
+        // act
+        let standings = wizard.standings_with(&[TieBreaker::MostBidsMade]);
+
+        // assert
+        assert_that!(places(&standings)).contains_exactly_in_order(vec![(1, 0), (2, 2), (3, 1)]);
+        assert_that!(standings[0].bids_made).is_equal_to(3);
+    }
+
+    #[test]
+    fn best_last_round_breaks_tie() {
+        // arrange
+        let wizard = tied_game();
+
+        // act
+        let standings = wizard.standings_with(&[TieBreaker::BestLastRound]);
+
+        // assert
+        assert_that!(places(&standings)).contains_exactly_in_order(vec![(1, 2), (2, 0), (3, 1)]);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Card, PlayerId, Suit};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The cards played to a single trick, in the order they were played.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Trick {
+    trump: Option<Suit>,
+    plays: Vec<(PlayerId, Card)>,
+}
+
+impl Trick {
+    pub fn new(trump: Option<Suit>) -> Self {
+        Self {
+            trump,
+            plays: Vec::new(),
+        }
+    }
+
+    pub fn play(&mut self, player: PlayerId, card: Card) {
+        self.plays.push((player, card));
+    }
+
+    pub fn plays(&self) -> &[(PlayerId, Card)] {
+        &self.plays
+    }
+
+    pub fn trump(&self) -> Option<Suit> {
+        self.trump
+    }
+
+    pub fn len(&self) -> usize {
+        self.plays.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.plays.is_empty()
+    }
+
+    /// The suit everybody has to follow: the first numbered card, unless a Wizard was led
+    /// before it. Leading Jesters leave the choice to the next player.
+    pub fn led_suit(&self) -> Option<Suit> {
+        for (_, card) in &self.plays {
+            match card {
+                Card::Number { suit, .. } => return Some(*suit),
+                Card::Wizard => return None,
+                Card::Jester => {}
+            }
+        }
+
+        None
+    }
+
+    /// The cards of `hand` that may be played to this trick. Whoever holds the led suit has
+    /// to follow it, Wizards and Jesters may always be played.
+    pub fn legal_moves(&self, hand: &[Card]) -> Vec<Card> {
+        let led_suit = self.led_suit();
+        let can_follow = hand
+            .iter()
+            .any(|card| card.suit().is_some() && card.suit() == led_suit);
+
+        hand.iter()
+            .copied()
+            .filter(|card| !can_follow || card.suit().is_none() || card.suit() == led_suit)
+            .collect()
+    }
+
+    /// The player taking the trick so far. The first Wizard wins, then the highest trump,
+    /// then the highest card of the led suit. If only Jesters were played, the first one wins.
+    pub fn winner(&self) -> Option<PlayerId> {
+        if let Some((player, _)) = self.plays.iter().find(|(_, card)| *card == Card::Wizard) {
+            return Some(*player);
+        }
+
+        let led_suit = self.led_suit();
+        let rank = |card: &Card| match card {
+            Card::Number { suit, value } if Some(*suit) == self.trump => Some((2, *value)),
+            Card::Number { suit, value } if Some(*suit) == led_suit => Some((1, *value)),
+            _ => None,
+        };
+
+        let mut winner = self.plays.first().map(|(player, _)| (*player, None));
+        for (player, card) in &self.plays {
+            // equal cards from a second deck do not beat the first one
+            if let Some(rank) = rank(card) {
+                if Some(rank) > winner.and_then(|(_, best)| best) {
+                    winner = Some((*player, Some(rank)));
+                }
+            }
+        }
+
+        winner.map(|(player, _)| player)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assertor::*;
+
+    fn number(suit: Suit, value: u8) -> Card {
+        Card::Number { suit, value }
+    }
+
+    fn trick(trump: Option<Suit>, cards: &[Card]) -> Trick {
+        let mut trick = Trick::new(trump);
+        for (seat, card) in cards.iter().enumerate() {
+            trick.play(PlayerId(seat as u32), *card);
+        }
+        trick
+    }
+
+    #[test]
+    fn first_wizard_wins() {
+        // arrange
+        let trick = trick(
+            Some(Suit::Red),
+            &[number(Suit::Red, 13), Card::Wizard, Card::Wizard],
+        );
+
+        // act
+        let winner = trick.winner();
+
+        // assert
+        assert_that!(winner).is_equal_to(Some(PlayerId(1)));
+    }
+
+    #[test]
+    fn trump_beats_led_suit() {
+        // arrange
+        let trick = trick(
+            Some(Suit::Red),
+            &[
+                number(Suit::Blue, 13),
+                number(Suit::Red, 2),
+                number(Suit::Red, 1),
+            ],
+        );
+
+        // act
+        let winner = trick.winner();
+
+        // assert
+        assert_that!(winner).is_equal_to(Some(PlayerId(1)));
+    }
+
+    #[test]
+    fn highest_card_of_led_suit_wins_without_trump() {
+        // arrange
+        let trick = trick(
+            None,
+            &[
+                number(Suit::Blue, 5),
+                number(Suit::Green, 13),
+                number(Suit::Blue, 9),
+            ],
+        );
+
+        // act
+        let winner = trick.winner();
+
+        // assert
+        assert_that!(winner).is_equal_to(Some(PlayerId(2)));
+    }
+
+    #[test]
+    fn first_numbered_card_after_jester_sets_led_suit() {
+        // arrange
+        let trick = trick(
+            None,
+            &[Card::Jester, number(Suit::Green, 3), number(Suit::Blue, 13)],
+        );
+
+        // act
+        let winner = trick.winner();
+
+        // assert
+        assert_that!(trick.led_suit()).is_equal_to(Some(Suit::Green));
+        assert_that!(winner).is_equal_to(Some(PlayerId(1)));
+    }
+
+    #[test]
+    fn holder_of_led_suit_has_to_follow() {
+        // arrange
+        let trick = trick(None, &[Card::Jester, number(Suit::Green, 3)]);
+        let hand = [number(Suit::Blue, 1), number(Suit::Green, 7), Card::Wizard];
+
+        // act
+        let legal_moves = trick.legal_moves(&hand);
+
+        // assert
+        assert_that!(legal_moves)
+            .contains_exactly_in_order(vec![number(Suit::Green, 7), Card::Wizard]);
+    }
+
+    #[test]
+    fn anything_may_be_played_after_a_wizard_lead() {
+        // arrange
+        let trick = trick(None, &[Card::Wizard, number(Suit::Green, 3)]);
+        let hand = [number(Suit::Blue, 1), number(Suit::Green, 7)];
+
+        // act
+        let legal_moves = trick.legal_moves(&hand);
+
+        // assert
+        assert_that!(legal_moves).contains_exactly_in_order(hand.to_vec());
+    }
+
+    #[test]
+    fn first_jester_wins_if_all_are_jesters() {
+        // arrange
+        let trick = trick(Some(Suit::Red), &[Card::Jester, Card::Jester, Card::Jester]);
+
+        // act
+        let winner = trick.winner();
+
+        // assert
+        assert_that!(winner).is_equal_to(Some(PlayerId(0)));
+    }
+}
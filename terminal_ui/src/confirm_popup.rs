@@ -0,0 +1,62 @@
+use crate::journal;
+use crate::ui_app::{self, Confirm};
+use crossterm::event::{KeyCode, KeyEvent};
+use tui::backend::Backend;
+use tui::layout::{Alignment, Rect};
+use tui::style::{Color, Modifier, Style};
+use tui::widgets::{Block, Borders, Clear, Paragraph};
+use tui::Frame;
+
+/// Returns `true` if the application should exit.
+pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) -> bool {
+    let confirmed = matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y'));
+
+    match app.confirm.take() {
+        Some(Confirm::Quit) => return confirmed,
+        Some(Confirm::Resume(game, bots)) => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.resume_game(*game, bots),
+            KeyCode::Char('n') | KeyCode::Char('N') => {
+                if let Err(err) = journal::discard() {
+                    app.hint = err.to_string();
+                }
+            }
+            _ => app.confirm = Some(Confirm::Resume(game, bots)),
+        },
+        None => {}
+    }
+
+    false
+}
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
+    let question = match app.confirm {
+        Some(Confirm::Quit) => "Quit the game? (y/n)",
+        Some(Confirm::Resume(..)) => "Resume the unfinished game? (y/n)",
+        None => return,
+    };
+
+    let size = f.size();
+    let width = (question.len() as u16 + 4).min(size.width);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height / 2).saturating_sub(1),
+        width,
+        3,
+    );
+
+    let paragraph = Paragraph::new(question)
+        .alignment(Alignment::Center)
+        .style(
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
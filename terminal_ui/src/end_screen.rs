@@ -0,0 +1,102 @@
+use crate::ui_app;
+use crossterm::event::{KeyCode, KeyEvent};
+use rusted_wizard_core::{TieBreaker, WizardBuilder, WizardState};
+use tui::backend::Backend;
+use tui::layout::{Alignment, Rect};
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Paragraph};
+use tui::Frame;
+
+const TIE_BREAKERS: [TieBreaker; 2] = [TieBreaker::MostBidsMade, TieBreaker::BestLastRound];
+
+/// Enter starts a rematch with the same players and rules. Returns `true` if the key was handled.
+pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) -> bool {
+    let game = match app.game.as_ref() {
+        Some(game) if game.state == WizardState::End => game,
+        _ => return false,
+    };
+
+    if key.code == KeyCode::Enter {
+        let mut rematch = WizardBuilder::new()
+            .players(game.players.iter().map(|player| player.name()))
+            .rules(*game.rules())
+            .length(game.length())
+            .round_limit(game.round_count)
+            .trick_entry(game.trick_entry());
+        if let Some(seed) = app.seed {
+            rematch = rematch.seed(seed);
+        }
+
+        match rematch.build() {
+            Ok(game) => {
+                app.hint.clear();
+                app.start_game(game, app.bots.clone());
+            }
+            Err(err) => app.hint = err.to_string(),
+        }
+    }
+
+    true
+}
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
+    let game = match app.game.as_ref() {
+        Some(game) if game.state == WizardState::End => game,
+        _ => return,
+    };
+
+    let mut text = vec![
+        Spans::from(Span::styled(
+            "Final standings",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Spans::default(),
+    ];
+    for standing in game.standings_with(&TIE_BREAKERS) {
+        let name = game
+            .player(standing.player)
+            .map(|player| player.name())
+            .unwrap_or_default();
+        text.push(Spans::from(format!(
+            "{}. {}: {} points, {} bids made",
+            standing.place, name, standing.points, standing.bids_made
+        )));
+    }
+    text.push(Spans::default());
+    text.push(Spans::from("Press Enter for a rematch or Esc to quit"));
+
+    let size = f.size();
+    let content_width = text.iter().map(|line| line.width()).max().unwrap_or(0) as u16;
+    let width = (content_width + 4).min(size.width);
+    let height = (text.len() as u16 + 2).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let paragraph = Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+    f.render_widget(paragraph, area);
+
+    if !app.hint.is_empty() {
+        let hint_area = Rect::new(
+            2,
+            size.height.saturating_sub(1),
+            size.width.saturating_sub(4),
+            1,
+        );
+        f.render_widget(
+            Paragraph::new(format!("Hint: {}", app.hint)).style(Style::default().fg(Color::Red)),
+            hint_area,
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use rusted_wizard_core::{save, GameCommand, Wizard, WizardState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+
+const JOURNAL_FILE: &str = "wizard_journal.jsonl";
+
+/// One line of the journal; a journal starts with the game it was created from.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Entry {
+    Start(Value),
+    /// Seats played by the computer, from here on.
+    Bots(Vec<bool>),
+    Command(GameCommand),
+    Undo,
+    Redo,
+}
+
+pub struct Journal {
+    file: File,
+}
+
+impl Journal {
+    pub fn create(game: &Wizard) -> io::Result<Self> {
+        let start =
+            save::to_value(game).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+
+        let mut journal = Self {
+            file: File::create(JOURNAL_FILE)?,
+        };
+        journal.append(&Entry::Start(start))?;
+
+        Ok(journal)
+    }
+
+    pub fn open() -> io::Result<Self> {
+        let file = OpenOptions::new().append(true).open(JOURNAL_FILE)?;
+
+        Ok(Self { file })
+    }
+
+    pub fn record(&mut self, command: &GameCommand) -> io::Result<()> {
+        self.append(&Entry::Command(command.clone()))
+    }
+
+    pub fn record_bots(&mut self, bots: &[bool]) -> io::Result<()> {
+        self.append(&Entry::Bots(bots.to_vec()))
+    }
+
+    pub fn record_undo(&mut self) -> io::Result<()> {
+        self.append(&Entry::Undo)
+    }
+
+    pub fn record_redo(&mut self) -> io::Result<()> {
+        self.append(&Entry::Redo)
+    }
+
+    fn append(&mut self, entry: &Entry) -> io::Result<()> {
+        let line = serde_json::to_string(entry)?;
+        writeln!(self.file, "{}", line)?;
+
+        // make sure the entry survives a crash of the terminal
+        self.file.sync_data()
+    }
+}
+
+/// Replays the journal left behind by a previous session, if that game is not finished yet.
+/// Returns the game together with the seats played by the computer.
+pub fn unfinished_game() -> Option<(Wizard, Vec<bool>)> {
+    let file = File::open(JOURNAL_FILE).ok()?;
+    let mut game: Option<Wizard> = None;
+    let mut bots = vec![];
+
+    for line in BufReader::new(file).lines() {
+        // a crash while writing may leave a truncated last line behind
+        let entry = match line.ok().and_then(|line| serde_json::from_str(&line).ok()) {
+            Some(entry) => entry,
+            None => break,
+        };
+
+        match (entry, game.as_mut()) {
+            (Entry::Start(start), _) => game = save::from_value(start).ok(),
+            (Entry::Bots(seats), Some(_)) => bots = seats,
+            (Entry::Command(command), Some(game)) => {
+                if game.apply(command).is_err() {
+                    break;
+                }
+            }
+            (Entry::Undo, Some(game)) => {
+                let _ = game.undo();
+            }
+            (Entry::Redo, Some(game)) => {
+                let _ = game.redo();
+            }
+            (_, None) => break,
+        }
+    }
+
+    game.filter(|game| game.state != WizardState::End)
+        .map(|game| (game, bots))
+}
+
+pub fn discard() -> io::Result<()> {
+    fs::remove_file(JOURNAL_FILE)
+}
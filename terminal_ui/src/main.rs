@@ -1,27 +1,27 @@
+mod confirm_popup;
+mod end_screen;
+mod journal;
 mod player_count_input_popup;
 mod player_name_input_popup;
+mod round_summary_popup;
 mod ui_app;
 mod playground;
+mod save_game;
 
-use rusted_wizard_core;
+use rusted_wizard_core::{GameCommand, TrickEntry};
 use std::{error::Error, io};
 use tui::{
     backend::{Backend, CrosstermBackend},
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::Span,
-    widgets::{Block, Borders, Clear, Paragraph},
+    style::{Color, Style},
+    widgets::Block,
     Frame, Terminal,
 };
-use unicode_width::UnicodeWidthStr;
 
-use crossterm::event::KeyModifiers;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyModifiers},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use rusted_wizard_core::{Wizard, WizardState};
 
 fn main() -> Result<(), Box<dyn Error>> {
     // setup terminal
@@ -33,6 +33,10 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // create app and run it
     let mut app = ui_app::App::new();
+    app.seed = seed_argument();
+    if let Some((game, bots)) = journal::unfinished_game() {
+        app.confirm = Some(ui_app::Confirm::Resume(Box::new(game), bots));
+    }
     let res = run_app(&mut terminal, &mut app);
 
     // restore terminal
@@ -51,21 +55,103 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+/// The seed passed as `--seed <seed>` to replay the deals of a shared game.
+fn seed_argument() -> Option<u64> {
+    let args: Vec<String> = std::env::args().collect();
+    let position = args.iter().position(|arg| arg == "--seed")?;
+    args.get(position + 1)?.parse().ok()
+}
+
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut ui_app::App) -> io::Result<()> {
     loop {
-        terminal.draw(|f| ui(f, &app))?;
+        terminal.draw(|f| ui(f, app))?;
 
         if let Event::Key(key) = event::read()? {
+            if app.confirm.is_some() {
+                if confirm_popup::handle_input(app, key) {
+                    return Ok(());
+                }
+                continue;
+            }
+
             if key.code == KeyCode::Esc {
-                return Ok(());
+                app.confirm = Some(ui_app::Confirm::Quit);
+                continue;
+            }
+
+            if handle_control_input(app, key) {
+                continue;
+            }
+
+            if end_screen::handle_input(app, key) {
+                continue;
             }
 
+            playground::handle_input(app, key);
             player_name_input_popup::handle_input(app, key);
             player_count_input_popup::handle_input(app, key);
         }
     }
 }
 
+fn handle_control_input(app: &mut ui_app::App, key: KeyEvent) -> bool {
+    if !key.modifiers.contains(KeyModifiers::CONTROL) {
+        return false;
+    }
+
+    match key.code {
+        KeyCode::Char('s') => save_game::save(app),
+        KeyCode::Char('o') => save_game::load(app),
+        KeyCode::Char('t') if app.bots.contains(&true) => {
+            app.hint = String::from("Bots can only play their cards, so card play stays on");
+        }
+        KeyCode::Char('t') => {
+            let trick_entry = match app.game.as_ref().map(|game| game.trick_entry()) {
+                Some(TrickEntry::Totals) => TrickEntry::Winners,
+                Some(TrickEntry::Winners) => TrickEntry::Cards,
+                _ => TrickEntry::Totals,
+            };
+
+            app.input.clear();
+            app.hint = match app.apply(GameCommand::SetTrickEntry(trick_entry)) {
+                Ok(_) => String::from(match trick_entry {
+                    TrickEntry::Totals => "Entering the trick totals of every player",
+                    TrickEntry::Winners => "Recording the winner of every trick",
+                    TrickEntry::Cards => "Playing the cards of every trick",
+                }),
+                Err(err) => err.to_string(),
+            };
+        }
+        KeyCode::Char('b') => {
+            if !player_name_input_popup::toggle_bot(app) {
+                return false;
+            }
+        }
+        KeyCode::Char('g') => {
+            app.hint = match app.game.as_ref().and_then(|game| game.seed()) {
+                Some(seed) => format!("Seed of this game: {}, replay it with --seed {}", seed, seed),
+                None => String::from("This game has no seed"),
+            };
+        }
+        KeyCode::Char('z') | KeyCode::Char('y') => {
+            let result = if key.code == KeyCode::Char('z') {
+                app.undo()
+            } else {
+                app.redo()
+            };
+
+            app.input.clear();
+            app.hint = match result {
+                Ok(_) => String::new(),
+                Err(err) => err.to_string(),
+            };
+        }
+        _ => return false,
+    }
+
+    true
+}
+
 fn ui<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
     let block = Block::default()
         .style(Style::default().bg(Color::Rgb(50, 50, 50)));
@@ -74,4 +160,7 @@ fn ui<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
     player_count_input_popup::draw(f, app);
     player_name_input_popup::draw(f, app);
     playground::draw(f, app);
+    end_screen::draw(f, app);
+    round_summary_popup::draw(f, app);
+    confirm_popup::draw(f, app);
 }
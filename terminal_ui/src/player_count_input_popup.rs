@@ -1,6 +1,6 @@
 use crate::ui_app;
 use crossterm::event::{KeyCode, KeyEvent};
-use rusted_wizard_core::Wizard;
+use rusted_wizard_core::{GameCommand, GameLength, RuleSet, Wizard};
 use tui::backend::Backend;
 use tui::layout::{Constraint, Direction, Layout};
 use tui::style::{Color, Modifier, Style};
@@ -9,6 +9,22 @@ use tui::widgets::{Block, Borders, Clear, Paragraph};
 use tui::Frame;
 use unicode_width::UnicodeWidthStr;
 
+const GAME_LENGTHS: [(GameLength, &str); 8] = [
+    (GameLength::Full, "Full game"),
+    (GameLength::MaxRounds(5), "At most 5 rounds"),
+    (GameLength::MaxRounds(10), "At most 10 rounds"),
+    (GameLength::EvenRounds, "Even rounds only"),
+    (GameLength::OddRounds, "Odd rounds only"),
+    (GameLength::UpAndDown, "Up and down"),
+    (GameLength::StartingAt(3), "Start at round 3"),
+    (GameLength::StartingAt(5), "Start at round 5"),
+];
+
+fn player_count_hint() -> String {
+    let player_counts = RuleSet::supported_player_counts();
+    format!("Player count must be between (including) {} and {}", player_counts.start(), player_counts.end())
+}
+
 pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
     if app.game.is_some() { return; }
 
@@ -20,12 +36,24 @@ pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
 
     match key.code {
         KeyCode::Enter => match app.player_count.parse::<usize>() {
-            Ok(player_count) => {
-                app.game = Option::from(Wizard::new(player_count));
-                for _ in 0..player_count {
-                    app.player_names.push(String::new());
-                }
-            }
+            Ok(player_count) => match RuleSet::for_player_count(player_count) {
+                Some(rules) => match Wizard::try_new(player_count, rules) {
+                    Ok(wizard) => {
+                        app.start_game(wizard, vec![]);
+                        let (length, _) = GAME_LENGTHS[app.game_length_index];
+                        if let Err(err) = app.apply(GameCommand::SetGameLength(length)) {
+                            app.hint = err.to_string();
+                        }
+                        if let Some(seed) = app.seed {
+                            if let Err(err) = app.apply(GameCommand::SetSeed(seed)) {
+                                app.hint = err.to_string();
+                            }
+                        }
+                    }
+                    Err(err) => app.hint = err.to_string(),
+                },
+                None => app.hint = player_count_hint(),
+            },
             Err(_) => app.hint = String::from("No valid input!"),
         },
         KeyCode::Char(c) => {
@@ -34,11 +62,12 @@ pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
 
             match temp.parse::<usize>() {
                 Ok(val) => {
-                    if val >= 3 && val <= 6 {
+                    // a single digit may still become a valid two digit count
+                    if val > 0 && val <= *RuleSet::supported_player_counts().end() {
                         app.player_count.push(c);
                         app.hint = String::new();
                     } else {
-                        app.hint = String::from("Player count must be between (including) 3 and 6");
+                        app.hint = player_count_hint();
                     }
                 }
                 Err(_) => {
@@ -49,11 +78,17 @@ pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
         KeyCode::Backspace => {
             app.player_count.pop();
         }
+        KeyCode::Down => {
+            app.game_length_index = (app.game_length_index + 1) % GAME_LENGTHS.len();
+        }
+        KeyCode::Up => {
+            app.game_length_index = (app.game_length_index + GAME_LENGTHS.len() - 1) % GAME_LENGTHS.len();
+        }
         _ => {}
     }
 }
 
-pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
     if app.game.is_none() {
         // we only need to render if no game is initialized
         let size = f.size();
@@ -64,6 +99,7 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
                     Constraint::Percentage(50),
                     Constraint::Length(3),
                     Constraint::Length(1),
+                    Constraint::Length(1),
                     Constraint::Percentage(50),
                 ]
                     .as_ref(),
@@ -82,11 +118,18 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
             );
 
         let text_area = horizontal_layout.split(popup_layout[1])[1];
-        let hint_area = horizontal_layout.split(popup_layout[2])[1];
+        let length_area = horizontal_layout.split(popup_layout[2])[1];
+        let hint_area = horizontal_layout.split(popup_layout[3])[1];
 
         f.render_widget(Clear, text_area); //this clears out the background
+        f.render_widget(Clear, length_area); //this clears out the background
         f.render_widget(Clear, hint_area); //this clears out the background
 
+        let (_, length_name) = GAME_LENGTHS[app.game_length_index];
+        let length = Paragraph::new(format!("Length: < {} >", length_name))
+            .style(Style::default().fg(Color::White));
+        f.render_widget(length, length_area);
+
         let input = Paragraph::new(app.player_count.clone())
             .style(Style::default().fg(Color::White))
             .block(
@@ -102,10 +145,10 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
 
         if !app.hint.is_empty() {
             let text = vec![
-                Span::from(Span::styled(
+                Span::styled(
                     "Hint: ",
                     Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
-                )),
+                ),
                 Span::from(app.hint.clone()),
             ];
 
@@ -1,29 +1,46 @@
 use crate::ui_app;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
-use rusted_wizard_core::WizardState;
+use rusted_wizard_core::{GameCommand, PendingInput, TrickEntry, WizardState};
 use tui::backend::Backend;
-use tui::layout::{Constraint, Direction, Layout};
+use tui::layout::{Constraint, Direction, Layout, Rect};
 use tui::style::Color::{Black, Gray, White};
-use tui::style::{Color, Modifier, Style};
-use tui::text::Span;
-use tui::widgets::{Block, Borders, Clear, Paragraph};
+use tui::style::{Color, Style};
+use tui::widgets::{Block, Borders, Paragraph};
 use tui::Frame;
 use unicode_width::UnicodeWidthStr;
 
-pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
-    if app.game.is_some() {
-        let mut wizard = app.game.as_mut().unwrap();
+/// Marks the selected seat as played by the computer or a person again, returns false if no
+/// names are being entered.
+pub fn toggle_bot(app: &mut ui_app::App) -> bool {
+    if app.game.as_ref().map(|game| game.state) != Some(WizardState::Init) {
+        return false;
+    }
+
+    let seat = app.player_name_index;
+    app.bots[seat] = !app.bots[seat];
+    if app.bots[seat] && app.player_names[seat].is_empty() {
+        app.player_names[seat] = format!("Bot {}", seat + 1);
+    }
+
+    true
+}
 
-        if wizard.state != WizardState::Init {
+pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
+    if let Some((state, player_count)) = app
+        .game
+        .as_ref()
+        .map(|game| (game.state, game.player_count))
+    {
+        if state != WizardState::Init {
             return;
         }
 
         match key.code {
             KeyCode::Tab => {
                 let index = if key.modifiers.contains(KeyModifiers::SHIFT) {
-                    (app.player_name_index - 1) % wizard.player_count
+                    (app.player_name_index - 1) % player_count
                 } else {
-                    (app.player_name_index + 1) % wizard.player_count
+                    (app.player_name_index + 1) % player_count
                 };
                 app.player_name_index = index;
             }
@@ -36,14 +53,25 @@ pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
             KeyCode::Enter => {
                 let is_ok = app.player_names.iter().all(|pn| !pn.is_empty());
                 if is_ok {
-                    for _ in 0..wizard.player_count {
-                        wizard.play(
-                            |player_index| app.player_names[player_index].clone(),
-                            |_, _| 0,
-                        );
+                    // after an undo only the remaining seats still need to be added
+                    while let Some(PendingInput::PlayerName { seat }) =
+                        app.game.as_ref().map(|game| game.pending_input())
+                    {
+                        let name = app.player_names[seat].clone();
+                        if let Err(err) = app.apply(GameCommand::AddPlayer(name)) {
+                            app.hint = err.to_string();
+                            break;
+                        }
+                    }
+                    // bots need the cards to play
+                    if app.bots.contains(&true) {
+                        app.record_bots();
+                        if let Err(err) = app.apply(GameCommand::SetTrickEntry(TrickEntry::Cards)) {
+                            app.hint = err.to_string();
+                        }
                     }
                 } else {
-                    app.player_name_index = (app.player_name_index + 1) % wizard.player_count;
+                    app.player_name_index = (app.player_name_index + 1) % player_count;
                 }
             }
             _ => {}
@@ -51,10 +79,8 @@ pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
     }
 }
 
-pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
-    if app.game.is_some() {
-        let mut game = app.game.as_ref().unwrap();
-
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
+    if let Some(game) = app.game.as_ref() {
         if game.state == WizardState::Init {
             let mut vertical_constraints = vec![Constraint::Percentage(40)];
             for _ in 0..game.player_count {
@@ -70,7 +96,7 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
 
             let vertical_layout = Layout::default()
                 .direction(Direction::Vertical)
-                .constraints(vertical_constraints.as_ref());
+                .constraints(vertical_constraints.as_slice());
             let horizontal_layout = Layout::default()
                 .direction(Direction::Horizontal)
                 .constraints(horizontal_constraints)
@@ -83,9 +109,9 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
             let mut bg_area = Layout::default()
                 .direction(Direction::Vertical)
                 .constraints(
-                    vec![
+                    [
                         Constraint::Percentage(40),
-                        Constraint::Length(game.player_count.clone() as u16),
+                        Constraint::Length(game.player_count as u16),
                         Constraint::Percentage(50),
                     ]
                     .as_ref(),
@@ -97,6 +123,13 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
             bg_area.y -= 1;
             f.render_widget(block, bg_area);
 
+            let help_area = Rect::new(bg_area.x, bg_area.y + bg_area.height, bg_area.width, 1);
+            let help =
+                Paragraph::new("Ctrl+B: computer plays this seat").style(Style::default().fg(Gray));
+            f.render_widget(help, help_area);
+
+            // "12:" needs one column more than "6:"
+            let label_width = game.player_count.to_string().len() as u16 + 1;
             for player_index in 0..game.player_count {
                 let name = &app.player_names[player_index];
 
@@ -106,12 +139,17 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
                     Style::default().fg(White)
                 };
 
-                let paragraph = Paragraph::new(name.clone()).style(style);
+                let text = if app.bots[player_index] {
+                    format!("{} (bot)", name)
+                } else {
+                    name.clone()
+                };
+                let paragraph = Paragraph::new(text).style(style);
                 let mut area = vertical_layout.split(horizontal_layout[1])[player_index + 1];
-                let mut index_area = area.clone();
-                index_area.width = 2;
-                area.x += 3;
-                area.width -= 3;
+                let mut index_area = area;
+                index_area.width = label_width;
+                area.x += label_width + 1;
+                area.width -= label_width + 1;
 
                 let index_string = (player_index + 1).to_string() + ":";
                 let index_paragraph = Paragraph::new(index_string).style(style);
@@ -122,7 +160,7 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
 
             let area = vertical_layout.split(horizontal_layout[1])[app.player_name_index + 1];
             let x_offset = app.player_names[app.player_name_index].width() as u16;
-            f.set_cursor(area.x + x_offset + 3, area.y);
+            f.set_cursor(area.x + x_offset + label_width + 1, area.y);
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::ui_app;
 use crossterm::event::{KeyCode, KeyEvent};
-use rusted_wizard_core::{Wizard, WizardState};
+use rusted_wizard_core::{Card, GameCommand, GameError, PendingInput, PlayerId, PlayerStrategy, RoundRecord, Suit, TrickEntry, Wizard, WizardState};
 use tui::backend::Backend;
 use tui::layout::{Constraint, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
@@ -8,17 +8,223 @@ use tui::text::Span;
 use tui::widgets::{Block, Borders, Clear, Paragraph};
 use tui::Frame;
 use unicode_width::UnicodeWidthStr;
-use Vec;
 use tui::style::Color::{Black, White};
 
-pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {}
+pub fn handle_input(app: &mut ui_app::App, key: KeyEvent) {
+    let game = match app.game.as_ref() {
+        Some(game) => game,
+        None => return,
+    };
 
-pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
+    match game.state {
+        WizardState::NextRound | WizardState::EndRound => {
+            if key.code == KeyCode::Enter {
+                app.hint.clear();
+                if let Err(err) = app.apply(GameCommand::StartNextRound) {
+                    app.hint = err.to_string();
+                }
+            }
+        }
+        WizardState::Playing => match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                app.input.push(c);
+            }
+            KeyCode::Backspace => {
+                app.input.pop();
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                let player_count = game.players.len().max(1);
+                let seat = app.entry_seat.or_else(|| pending_seat(game)).unwrap_or(0);
+                app.entry_seat = Some(if key.code == KeyCode::Tab {
+                    (seat + 1) % player_count
+                } else {
+                    (seat + player_count - 1) % player_count
+                });
+            }
+            KeyCode::Enter => {
+                let selected = app.entry_seat.and_then(|seat| game.players.get(seat)).map(|player| player.id());
+                let bot_command = match (selected, pending_player(game)) {
+                    (None, Some(player)) if app.is_bot(player) => {
+                        let mut bot = app.bot;
+                        game.current_round.as_ref().and_then(|round| bot.command(round, player))
+                    }
+                    _ => None,
+                };
+                let command = match game.pending_input() {
+                    _ if bot_command.is_some() => bot_command,
+                    PendingInput::Bid { range, .. } => match app.input.parse::<u8>() {
+                        Ok(bid) => Some(match selected {
+                            Some(player) => GameCommand::SetBid { player, bid },
+                            None => GameCommand::PlaceBid(bid),
+                        }),
+                        Err(_) => {
+                            app.hint = format!("Bid must be between {} and {}", range.start(), range.end());
+                            None
+                        }
+                    },
+                    PendingInput::Tricks { range, .. } => match app.input.parse::<u8>() {
+                        Ok(tricks) => Some(match selected {
+                            Some(player) => GameCommand::SetTricks { player, tricks },
+                            None => GameCommand::ReportTricks(tricks),
+                        }),
+                        Err(_) => {
+                            app.hint = format!("Tricks must be between {} and {}", range.start(), range.end());
+                            None
+                        }
+                    },
+                    PendingInput::TrickWinner { .. } => {
+                        let winner = app.input.parse::<usize>().ok()
+                            .and_then(|seat| seat.checked_sub(1))
+                            .and_then(|seat| game.players.get(seat));
+                        match winner {
+                            Some(player) => Some(GameCommand::RecordTrickWinner(player.id())),
+                            None => {
+                                app.hint = format!("Winner must be a seat between 1 and {}", game.players.len());
+                                None
+                            }
+                        }
+                    }
+                    PendingInput::Trump { .. } => {
+                        let suit = app.input.parse::<usize>().ok()
+                            .and_then(|index| index.checked_sub(1))
+                            .and_then(|index| Suit::ALL.get(index));
+                        match suit {
+                            Some(suit) => Some(GameCommand::ChooseTrump(*suit)),
+                            None => {
+                                app.hint = format!("Trump must be between 1 and {}", Suit::ALL.len());
+                                None
+                            }
+                        }
+                    }
+                    PendingInput::Card { player } => {
+                        let hand = game.current_round.as_ref().and_then(|round| round.hand(player)).unwrap_or_default();
+                        let card = app.input.parse::<usize>().ok()
+                            .and_then(|index| index.checked_sub(1))
+                            .and_then(|index| hand.get(index));
+                        match card {
+                            Some(card) => Some(GameCommand::PlayCard(*card)),
+                            None => {
+                                app.hint = format!("Card must be between 1 and {}", hand.len());
+                                None
+                            }
+                        }
+                    }
+                    _ => Some(GameCommand::ScoreRound),
+                };
+
+                if let Some(command) = command {
+                    app.hint.clear();
+                    match app.apply(command) {
+                        Err(err @ (GameError::TricksExceedRound { .. } | GameError::TricksMismatch { .. })) => {
+                            app.hint = format!("{}, press Ctrl+Z to correct an earlier player", err);
+                        }
+                        Err(err) => app.hint = err.to_string(),
+                        Ok(_) => app.entry_seat = None,
+                    }
+                    app.input.clear();
+                }
+            }
+            _ => {}
+        },
+        WizardState::Init | WizardState::End => {}
+    }
+}
+
+fn pending_player(game: &Wizard) -> Option<PlayerId> {
+    match game.pending_input() {
+        PendingInput::Bid { player, .. }
+        | PendingInput::Tricks { player, .. }
+        | PendingInput::Trump { player }
+        | PendingInput::Card { player } => Some(player),
+        _ => None,
+    }
+}
+
+fn pending_seat(game: &Wizard) -> Option<usize> {
+    let player = pending_player(game)?;
+    game.players.iter().position(|other| other.id() == player)
+}
+
+fn prompt(app: &ui_app::App, game: &Wizard) -> String {
+    let entry_seat = app.entry_seat;
+    let name = |player| game.player(player).map(|player| player.name()).unwrap_or_default();
+    if let Some(player) = pending_player(game).filter(|player| entry_seat.is_none() && app.is_bot(*player)) {
+        return format!("{} (bot) is next, press Enter", name(player));
+    }
+    // another player than the pending one may be picked with Tab
+    let selected = entry_seat
+        .filter(|seat| Some(*seat) != pending_seat(game))
+        .and_then(|seat| game.players.get(seat))
+        .map(|player| player.id());
+
+    match game.pending_input() {
+        PendingInput::Bid { player, range, forbidden } => match (selected, forbidden) {
+            (None, Some(forbidden)) => format!("Bid of {} ({}-{}, you may not bid {}): ", name(player), range.start(), range.end(), forbidden),
+            (selected, _) => format!("Bid of {} ({}-{}): ", name(selected.unwrap_or(player)), range.start(), range.end()),
+        },
+        PendingInput::Tricks { player, range } => {
+            format!("Tricks of {} ({}-{}): ", name(selected.unwrap_or(player)), range.start(), range.end())
+        }
+        PendingInput::TrickWinner { trick, of } => {
+            format!("Seat of the winner of trick {} of {} (1-{}): ", trick, of, game.players.len())
+        }
+        PendingInput::Trump { player } => {
+            let suits: Vec<String> = Suit::ALL.iter().enumerate().map(|(i, suit)| format!("{} {:?}", i + 1, suit)).collect();
+            format!("Trump chosen by {} ({}): ", name(player), suits.join(", "))
+        }
+        PendingInput::Card { player } => {
+            let cards = game.current_round.as_ref().and_then(|round| round.hand(player)).map_or(0, |hand| hand.len());
+            format!("Card of {} (1-{}): ", name(player), cards)
+        }
+        _ => match game.state {
+            WizardState::Playing => String::from("Press Enter to score the round"),
+            _ => String::from("Press Enter to start the next round"),
+        },
+    }
+}
+
+/// Trump, the trick on the table and the hand of the player to play, above the prompt.
+fn draw_cards<B: Backend>(f: &mut Frame<B>, app: &ui_app::App, game: &Wizard) {
+    let round = match game.current_round.as_ref() {
+        Some(round) if round.trick_entry() == TrickEntry::Cards && game.state == WizardState::Playing => round,
+        _ => return,
+    };
+    let name = |player| game.player(player).map(|player| player.name()).unwrap_or_default();
+
+    let trump = match (round.trump(), round.trump_card()) {
+        (Some(suit), _) => format!("{:?}", suit),
+        (None, Some(Card::Wizard)) => String::from("to be chosen"),
+        _ => String::from("none"),
+    };
+    let trick = round.tricks().last()
+        .map(|trick| trick.plays().iter().map(|(player, card)| format!("{} {}", name(*player), card)).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let table = format!("Trump: {}   Trick: {}", trump, trick.join(", "));
+
+    let hand = match pending_player(game) {
+        Some(player) if !app.is_bot(player) => {
+            let cards: Vec<String> = round.hand(player).unwrap_or_default().iter().enumerate()
+                .map(|(i, card)| format!("{} {}", i + 1, card))
+                .collect();
+            format!("Hand of {}: {}", name(player), cards.join("  "))
+        }
+        _ => String::new(),
+    };
+
+    let size = f.size();
+    for (row, text) in [(4, table), (3, hand)] {
+        let area = Rect::new(2, size.height.saturating_sub(row), size.width.saturating_sub(4), 1);
+        f.render_widget(Clear, area);
+        f.render_widget(Paragraph::new(text).style(Style::default().fg(White)), area);
+    }
+}
+
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
     if app.game.is_none() {
         return;
     }
 
-    let mut game = app.game.as_ref().unwrap();
+    let game = app.game.as_ref().unwrap();
     if game.state == WizardState::Init || game.state == WizardState::End { return; }
 
     // background
@@ -42,11 +248,11 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
 
     let column_layout = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints(column_constraints.as_ref())
+        .constraints(column_constraints.as_slice())
         .split(playground_area);
 
     for round_index in 0..game.round_count {
-        let str = (round_index + 1).to_string();
+        let str = game.schedule().get(round_index).copied().unwrap_or_default().to_string();
         let round_index_paragraph = Paragraph::new(str)
             .style(Style::default().add_modifier(if round_index == game.round_index {
                 Modifier::BOLD
@@ -59,19 +265,26 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
         f.render_widget(round_index_paragraph, round_index_area);
     }
 
-    for i in 0..game.player_count {
-        let player_area = column_layout[i];
+    let history: Vec<RoundRecord> = game.history().collect();
+    // with many players the columns only fit the totals
+    let show_bids = column_layout.first().is_some_and(|area| area.width >= 12);
+    for (i, player_area) in column_layout.iter().copied().enumerate() {
         let block = Block::default()
             .borders(Borders::LEFT);
         f.render_widget(block, player_area);
 
-        let player_name = app.player_names[i].clone(); // players of game will be rotated!
+        let is_dealer = game.current_round.as_ref().map(|round| round.dealer_seat()) == Some(i);
+        let player_name = if is_dealer {
+            format!("{} (dealer)", app.player_names[i])
+        } else {
+            app.player_names[i].clone()
+        };
         let name_paragraph = Paragraph::new(player_name)
             .block(Block::default().borders(Borders::BOTTOM))
             .style(Style::default().add_modifier(Modifier::BOLD).fg(White));
         let mut player_name_area = player_area;
         player_name_area.height = 2;
-        player_name_area.width -= 3;
+        player_name_area.width = player_name_area.width.saturating_sub(3);
         player_name_area.x += 2;
         f.render_widget(name_paragraph, player_name_area);
 
@@ -80,30 +293,59 @@ pub fn draw<'a, B: Backend>(f: &mut Frame<B>, app: &'a ui_app::App) {
             round_points_area.x += 2;
             round_points_area.y += 3 + (round_draw_index * multiplier) as u16;
             round_points_area.height = 1;
-            round_points_area.width -= 9;
+            round_points_area.width = round_points_area.width.saturating_sub(if show_bids { 9 } else { 3 });
 
             if round_draw_index > game.round_index {
                 round_points_area.y += upcoming_row_offset as u16;
             }
 
-            let round_points_str = if round_draw_index < game.round_index {
-                game.rounds[round_draw_index].players[i].points.to_string()
-            } else {
-                String::new()
+            let record = history.get(round_draw_index).and_then(|round| round.players.get(i));
+            let (round_points_str, round_tip_str, round_match_str) = match record {
+                Some(record) => (record.total.to_string(), record.bid.to_string(), record.tricks.to_string()),
+                None => (String::new(), String::new(), String::new()),
             };
             let round_points_paragraph = Paragraph::new(round_points_str)
                 .style(Style::default().bg(Color::Gray));
             f.render_widget(round_points_paragraph, round_points_area);
+            if !show_bids {
+                continue;
+            }
 
             let round_tip_area = Rect::new(round_points_area.x + round_points_area.width + 1, round_points_area.y, 2, 1);
-            let round_tip_paragraph = Paragraph::new(String::new())
+            let round_tip_paragraph = Paragraph::new(round_tip_str)
                 .style(Style::default().bg(Color::Gray));
             f.render_widget(round_tip_paragraph, round_tip_area);
 
             let round_match_area = Rect::new(round_tip_area.x + round_tip_area.width + 1, round_points_area.y, 2, 1);
-            let round_match_paragraph = Paragraph::new(String::new())
+            let round_match_paragraph = Paragraph::new(round_match_str)
                 .style(Style::default().bg(Color::Gray));
             f.render_widget(round_match_paragraph, round_match_area);
         }
     }
+
+    let size = f.size();
+    draw_cards(f, app, game);
+
+    let prompt_str = prompt(app, game);
+    let prompt_width = prompt_str.width() as u16;
+    let prompt_area = Rect::new(2, size.height - 2, size.width - 4, 1);
+    let prompt_paragraph = Paragraph::new(prompt_str + &app.input)
+        .style(Style::default().add_modifier(Modifier::BOLD).fg(White));
+    f.render_widget(prompt_paragraph, prompt_area);
+
+    if !app.hint.is_empty() {
+        let hint_area = Rect::new(2, size.height - 1, size.width - 4, 1);
+        let text = vec![
+            Span::styled(
+                "Hint: ",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::from(app.hint.clone()),
+        ];
+        f.render_widget(Block::default().title(text), hint_area);
+    }
+
+    if game.state == WizardState::Playing {
+        f.set_cursor(prompt_area.x + prompt_width + app.input.len() as u16, prompt_area.y);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,71 @@
+use crate::ui_app;
+use rusted_wizard_core::{RoundState, WizardState};
+use tui::backend::Backend;
+use tui::layout::Rect;
+use tui::style::{Color, Modifier, Style};
+use tui::text::{Span, Spans};
+use tui::widgets::{Block, Borders, Clear, Paragraph};
+use tui::Frame;
+use unicode_width::UnicodeWidthStr;
+
+/// Explains the points of the round that is about to be scored or was just scored.
+pub fn draw<B: Backend>(f: &mut Frame<B>, app: &ui_app::App) {
+    let game = match app.game.as_ref() {
+        Some(game) => game,
+        None => return,
+    };
+    let round = match game.current_round.as_ref() {
+        Some(round)
+            if game.state == WizardState::EndRound || round.state == RoundState::Checking =>
+        {
+            round
+        }
+        _ => return,
+    };
+
+    let lines: Vec<String> = round
+        .players
+        .iter()
+        .map(|player| {
+            let breakdown = round.score_breakdown(player);
+            format!(
+                "{}: bid {}, took {}: {}",
+                player.name(),
+                breakdown.bid,
+                breakdown.tricks,
+                breakdown
+            )
+        })
+        .collect();
+
+    let size = f.size();
+    let content_width = lines.iter().map(|line| line.width()).max().unwrap_or(0) as u16;
+    let width = (content_width + 4).min(size.width);
+    let height = (lines.len() as u16 + 2).min(size.height);
+    let area = Rect::new(
+        (size.width - width) / 2,
+        (size.height - height) / 2,
+        width,
+        height,
+    );
+
+    let text: Vec<Spans> = lines
+        .into_iter()
+        .map(|line| Spans::from(Span::raw(line)))
+        .collect();
+    let title = format!(" Round {} ({} cards) ", round.round_nr(), round.cards());
+    let paragraph = Paragraph::new(text)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .title(Span::styled(
+                    title,
+                    Style::default().add_modifier(Modifier::BOLD),
+                ))
+                .borders(Borders::ALL)
+                .style(Style::default().bg(Color::DarkGray)),
+        );
+
+    f.render_widget(Clear, area);
+    f.render_widget(paragraph, area);
+}
@@ -0,0 +1,56 @@
+use crate::ui_app;
+use rusted_wizard_core::save::{self, SaveError};
+use rusted_wizard_core::Wizard;
+use serde_json::Value;
+use std::fs;
+
+const SAVE_FILE: &str = "wizard_save.json";
+
+pub fn save(app: &mut ui_app::App) {
+    let game = match app.game.as_ref() {
+        Some(game) => game,
+        None => return,
+    };
+
+    // the seats played by the computer are kept next to the game, which does not know them
+    let json = save::to_value(game).and_then(|mut value| {
+        value["bots"] = Value::from(app.bots.clone());
+        Ok(serde_json::to_string_pretty(&value)?)
+    });
+
+    app.hint = match json.map(|json| fs::write(SAVE_FILE, json)) {
+        Ok(Ok(_)) => format!("Game saved to {}", SAVE_FILE),
+        Ok(Err(err)) => err.to_string(),
+        Err(err) => err.to_string(),
+    };
+}
+
+pub fn load(app: &mut ui_app::App) {
+    let (game, bots) = match fs::read_to_string(SAVE_FILE) {
+        Ok(json) => match read(&json) {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                app.hint = err.to_string();
+                return;
+            }
+        },
+        Err(err) => {
+            app.hint = err.to_string();
+            return;
+        }
+    };
+
+    app.start_game(game, bots);
+    app.hint = format!("Game loaded from {}", SAVE_FILE);
+}
+
+/// The saved game and its seats played by the computer, none for files saved without them.
+fn read(json: &str) -> Result<(Wizard, Vec<bool>), SaveError> {
+    let value: Value = serde_json::from_str(json)?;
+    let bots = value["bots"]
+        .as_array()
+        .map(|seats| seats.iter().filter_map(Value::as_bool).collect())
+        .unwrap_or_default();
+
+    Ok((save::from_value(value)?, bots))
+}
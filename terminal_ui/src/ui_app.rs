@@ -1,9 +1,29 @@
+use crate::journal::Journal;
+use rusted_wizard_core::{GameCommand, GameError, GameEvent, HeuristicBot, PlayerId, Wizard};
+
+pub enum Confirm {
+    Quit,
+    /// The unfinished game of the journal and its seats played by the computer.
+    Resume(Box<Wizard>, Vec<bool>),
+}
+
 pub struct App {
     pub game: Option<rusted_wizard_core::Wizard>,
     pub player_count: String,
+    pub game_length_index: usize,
     pub player_names: Vec<String>,
     pub player_name_index: usize,
     pub hint: String,
+    pub input: String,
+    /// Seat whose bid or tricks are entered next, `None` follows the bidding order.
+    pub entry_seat: Option<usize>,
+    /// Seats played by the computer, see [`HeuristicBot`].
+    pub bots: Vec<bool>,
+    pub bot: HeuristicBot,
+    /// Seed for new games given with `--seed`, otherwise every game gets a random one.
+    pub seed: Option<u64>,
+    pub journal: Option<Journal>,
+    pub confirm: Option<Confirm>,
 }
 
 impl App {
@@ -11,9 +31,116 @@ impl App {
         App {
             game: None,
             player_count: String::new(),
+            game_length_index: 0,
             player_names: vec![],
             player_name_index: 0,
             hint: String::new(),
+            input: String::new(),
+            entry_seat: None,
+            bots: vec![],
+            bot: HeuristicBot,
+            seed: None,
+            journal: None,
+            confirm: None,
         }
     }
+
+    /// Starts playing `game` with the computer in the `bots` seats and begins a new journal
+    /// for it.
+    pub fn start_game(&mut self, game: Wizard, bots: Vec<bool>) {
+        match Journal::create(&game) {
+            Ok(journal) => self.journal = Some(journal),
+            Err(err) => self.hint = format!("Journal not available: {}", err),
+        }
+
+        self.restore(game, bots);
+        if self.bots.contains(&true) {
+            self.record_bots();
+        }
+    }
+
+    /// Continues playing `game` and appends to the existing journal.
+    pub fn resume_game(&mut self, game: Wizard, bots: Vec<bool>) {
+        match Journal::open() {
+            Ok(journal) => self.journal = Some(journal),
+            Err(err) => self.hint = format!("Journal not available: {}", err),
+        }
+
+        self.restore(game, bots);
+    }
+
+    fn restore(&mut self, game: Wizard, bots: Vec<bool>) {
+        self.player_count = game.player_count.to_string();
+        self.player_names = vec![String::new(); game.player_count];
+        for (name, player) in self.player_names.iter_mut().zip(game.players.iter()) {
+            *name = player.name().to_string();
+        }
+        self.bots = bots;
+        self.bots.resize(game.player_count, false);
+        self.player_name_index = 0;
+        self.input.clear();
+        self.entry_seat = None;
+        self.game = Some(game);
+    }
+
+    pub fn is_bot(&self, player: PlayerId) -> bool {
+        let seat = self
+            .game
+            .as_ref()
+            .and_then(|game| game.players.iter().position(|other| other.id() == player));
+        seat.and_then(|seat| self.bots.get(seat))
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Writes the seats played by the computer to the journal, so a resumed game keeps them.
+    pub fn record_bots(&mut self) {
+        if let Some(journal) = self.journal.as_mut() {
+            if let Err(err) = journal.record_bots(&self.bots) {
+                self.hint = format!("Journal not written: {}", err);
+            }
+        }
+    }
+
+    pub fn apply(&mut self, command: GameCommand) -> Result<Vec<GameEvent>, GameError> {
+        let game = match self.game.as_mut() {
+            Some(game) => game,
+            None => return Ok(vec![]),
+        };
+
+        let events = game.apply(command.clone())?;
+        if let Some(journal) = self.journal.as_mut() {
+            if let Err(err) = journal.record(&command) {
+                self.hint = format!("Journal not written: {}", err);
+            }
+        }
+
+        Ok(events)
+    }
+
+    pub fn undo(&mut self) -> Result<(), GameError> {
+        if let Some(game) = self.game.as_mut() {
+            game.undo()?;
+            if let Some(journal) = self.journal.as_mut() {
+                if let Err(err) = journal.record_undo() {
+                    self.hint = format!("Journal not written: {}", err);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn redo(&mut self) -> Result<(), GameError> {
+        if let Some(game) = self.game.as_mut() {
+            game.redo()?;
+            if let Some(journal) = self.journal.as_mut() {
+                if let Err(err) = journal.record_redo() {
+                    self.hint = format!("Journal not written: {}", err);
+                }
+            }
+        }
+
+        Ok(())
+    }
 }